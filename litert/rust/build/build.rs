@@ -182,6 +182,7 @@ fn get_litert_runtime_library(
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo::rerun-if-changed=build/build.rs");
     println!("cargo::rerun-if-changed=wrapper.h");
+    println!("cargo::rerun-if-changed=shim/serialized_buffer.cc");
 
     if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
         info!("Manifest dir {}", manifest_dir);
@@ -197,6 +198,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     println!("cargo::rustc-link-lib=dylib=LiteRt");
 
+    cc::Build::new()
+        .cpp(true)
+        .file("shim/serialized_buffer.cc")
+        .compile("litert_rust_shim");
+
     check_tool_installed("clang")?;
     let bindings = bindgen::Builder::default()
         .header("wrapper.h")
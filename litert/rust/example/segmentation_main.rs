@@ -26,28 +26,34 @@ struct Args {
     output_image_path: String,
 }
 
-struct SegmentationModel {
-    model: litert::Model,
+struct SegmentationModel<'m> {
+    model: &'m litert::Model<'static>,
     env: litert::Environment,
-    compiled_model: litert::CompiledModel,
+    compiled_model: litert::CompiledModel<'m>,
 }
 
-impl SegmentationModel {
-    fn initialize(model_path: &str) -> Result<SegmentationModel, litert::Error> {
-        let m = litert::Model::create_model_from_file(model_path)?;
+impl<'m> SegmentationModel<'m> {
+    fn initialize(m: &'m litert::Model<'static>) -> Result<SegmentationModel<'m>, litert::Error> {
         let env = litert::EnvironmentBuilder::build_default()?;
         let options = litert::Options::create_with_accelerator(litert::LiteRtHwAccelerator::Cpu)?;
-        let cm = litert::CompiledModel::create(&env, &m, &options)?;
-        Ok(SegmentationModel { model: m, env: env, compiled_model: cm })
+        let cm = litert::CompiledModel::create(&env, m, &options)?;
+        Ok(SegmentationModel {
+            model: m,
+            env: env,
+            compiled_model: cm,
+        })
     }
     fn run(&self, input: &[f32], output: &mut [f32]) -> Result<(), litert::Error> {
-        let inputs = self.compiled_model.create_input_tensor_buffers(&self.env, &self.model, 0)?;
+        let mut inputs = self
+            .compiled_model
+            .create_input_tensor_buffers(&self.env, self.model, 0)?;
         println!("Input type: {:?}", inputs[0].element_type());
         inputs[0].write(input)?;
-        let outputs =
-            self.compiled_model.create_output_tensor_buffers(&self.env, &self.model, 0)?;
+        let mut outputs = self
+            .compiled_model
+            .create_output_tensor_buffers(&self.env, self.model, 0)?;
         println!("Output type: {:?}", outputs[0].element_type());
-        self.compiled_model.run(0, &inputs, &outputs)?;
+        self.compiled_model.run(0, &inputs, &mut outputs)?;
         outputs[0].read(output)?;
         Ok(())
     }
@@ -58,8 +64,12 @@ fn load_image(path: &str) -> ImageBuffer<image::Rgba<u8>, Vec<u8>> {
         .expect("Failed to read an input image")
         .decode()
         .expect("Failed to decode an input image");
-    let resized_image =
-        imageops::resize(&img, IMAGE_WIDTH, IMAGE_HEIGHT, imageops::FilterType::Lanczos3);
+    let resized_image = imageops::resize(
+        &img,
+        IMAGE_WIDTH,
+        IMAGE_HEIGHT,
+        imageops::FilterType::Lanczos3,
+    );
     resized_image
 }
 
@@ -102,7 +112,10 @@ fn save_image(tensor: &Vec<f32>, path: &str) {
 
     let image = ImageBuffer::from_fn(IMAGE_WIDTH, IMAGE_HEIGHT, |x, y| {
         let num_classes: usize = tensor.len() / IMAGE_WIDTH as usize / IMAGE_HEIGHT as usize;
-        assert!(num_classes < output_colors.len(), "Too many classes in the output tensor");
+        assert!(
+            num_classes < output_colors.len(),
+            "Too many classes in the output tensor"
+        );
         let start_index = num_classes * (y * IMAGE_WIDTH + x) as usize;
         let max_index = tensor[start_index..(start_index + num_classes)]
             .iter()
@@ -113,28 +126,40 @@ fn save_image(tensor: &Vec<f32>, path: &str) {
 
         output_colors[max_index]
     });
-    image.save_with_format(path, image::ImageFormat::Png).expect("Failed to save an output image");
+    image
+        .save_with_format(path, image::ImageFormat::Png)
+        .expect("Failed to save an output image");
 }
 
 fn main() {
     let args = Args::parse();
-    let model = SegmentationModel::initialize(args.model_path.as_str())
-        .expect("Failed to initialize model");
+    let m = litert::Model::create_model_from_file(args.model_path.as_str())
+        .expect("Failed to load model");
+    let model = SegmentationModel::initialize(&m).expect("Failed to initialize model");
     println!("Model initialized successfully!");
     let input_image = load_image(args.input_image_path.as_str());
     println!("Input image loaded successfully!");
     let input_tensor = image_to_tensor(&input_image);
-    for s in model.model.signatures().expect("Failed to get signature iterator") {
+    for s in model
+        .model
+        .signatures()
+        .expect("Failed to get signature iterator")
+    {
         let signature = s.expect("Failed to get signature");
         println!("Signature key: {:?}", signature.key());
         for input_name in signature.input_names().expect("Failed to get input names") {
             println!("Input name: {input_name:?}");
         }
-        for output_name in signature.output_names().expect("Failed to get output names") {
+        for output_name in signature
+            .output_names()
+            .expect("Failed to get output names")
+        {
             println!("Output name: {output_name:?}");
         }
         let mut output = vec![0.0f32; 2 * 3 * 256 * 256];
-        model.run(&input_tensor, &mut output).expect("Failed to get input");
+        model
+            .run(&input_tensor, &mut output)
+            .expect("Failed to get input");
         save_image(&output, args.output_image_path.as_str());
     }
 }
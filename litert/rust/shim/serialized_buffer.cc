@@ -0,0 +1,22 @@
+// Copyright 2025 Google LLC.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include <cstdint>
+
+// LiteRtSerializeModel() allocates the serialized buffer with `new uint8_t[]`
+// and the C API has no function to release it, so the Rust bindings release it
+// here with the matching `delete[]`.
+extern "C" void LiteRtRustDeleteSerializedBuffer(uint8_t* buffer) {
+  delete[] buffer;
+}
@@ -0,0 +1,594 @@
+//! Static analysis of models.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, ErrorCause};
+use crate::model::{Model, Quantization, Signature, Tensor};
+use crate::op_code::OpCode;
+use crate::op_options::OpOptions;
+use crate::ElementType;
+
+/// Statistics of a model: parameters, constant weights and estimated compute of its operations.
+///
+/// Weights shared by several tensors are counted once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelStats {
+    /// Number of elements of all constant tensors.
+    pub num_parameters: usize,
+    /// Bytes of constant weights by element type.
+    pub weight_bytes: HashMap<ElementType, usize>,
+    /// Number of operations by code.
+    pub op_histogram: HashMap<OpCode, usize>,
+    /// Statistics of every operation of every subgraph.
+    pub ops: Vec<OpStats>,
+}
+
+/// Statistics of an operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpStats {
+    pub subgraph_index: usize,
+    pub op_index: usize,
+    pub code: OpCode,
+    /// Estimated multiply-accumulate count, `None` for operations without an estimate or with
+    /// dynamic shapes.
+    pub macs: Option<u64>,
+    /// Estimated floating point operation count, two per MAC for convolutions and products, one
+    /// per MAC for pooling.
+    pub flops: Option<u64>,
+}
+
+impl ModelStats {
+    /// Collects the statistics of all subgraphs of the model.
+    pub fn new(model: &Model) -> Result<Self, Error> {
+        let mut stats = ModelStats {
+            num_parameters: 0,
+            weight_bytes: HashMap::new(),
+            op_histogram: HashMap::new(),
+            ops: Vec::new(),
+        };
+        let mut seen_weights = HashSet::new();
+        for (subgraph_index, subgraph) in model.subgraphs()?.enumerate() {
+            for (op_index, op) in subgraph?.ops()?.enumerate() {
+                let op = op?;
+                let code = op.code()?;
+                *stats.op_histogram.entry(code).or_insert(0) += 1;
+                let mut input_dims = Vec::new();
+                for input in op.inputs()? {
+                    let input = input?;
+                    let weights = input.weights()?;
+                    let bytes = weights.bytes();
+                    if !bytes.is_empty() && seen_weights.insert((bytes.as_ptr(), bytes.len())) {
+                        let element_type = input.element_type()?;
+                        *stats.weight_bytes.entry(element_type).or_insert(0) += bytes.len();
+                        stats.num_parameters += match static_dims(&input)? {
+                            Some(dims) => dims.iter().product(),
+                            None => element_type
+                                .bit_width()
+                                .map_or(0, |bit_width| bytes.len() * 8 / bit_width),
+                        };
+                    }
+                    input_dims.push(static_dims(&input)?);
+                }
+                let output_dims = op
+                    .outputs()?
+                    .map(|output| static_dims(&output?))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let macs = estimate_macs(code, &op.options()?, &input_dims, &output_dims);
+                let flops = macs.map(|macs| if is_pool(code) { macs } else { 2 * macs });
+                stats.ops.push(OpStats {
+                    subgraph_index,
+                    op_index,
+                    code,
+                    macs,
+                    flops,
+                });
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Returns the total bytes of constant weights.
+    pub fn total_weight_bytes(&self) -> usize {
+        self.weight_bytes.values().sum()
+    }
+
+    /// Returns the estimated multiply-accumulate count of the operations that have an estimate.
+    pub fn total_macs(&self) -> u64 {
+        self.ops.iter().filter_map(|op| op.macs).sum()
+    }
+
+    /// Returns the estimated floating point operation count of the operations that have an
+    /// estimate.
+    pub fn total_flops(&self) -> u64 {
+        self.ops.iter().filter_map(|op| op.flops).sum()
+    }
+}
+
+// Returns the dimensions of a tensor, `None` if the tensor is unranked or has dynamic dimensions.
+fn static_dims(tensor: &Tensor<'_>) -> Result<Option<Vec<usize>>, Error> {
+    match tensor.tensor_type() {
+        Ok(tensor_type) => Ok(tensor_type.shape.dims().iter().copied().collect()),
+        Err(e) if e.cause() == ErrorCause::UnrankedTensor => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn is_pool(code: OpCode) -> bool {
+    matches!(
+        code,
+        OpCode::TflAveragePool2d | OpCode::TflMaxPool2d | OpCode::TflL2Pool2d
+    )
+}
+
+// Estimates the multiply-accumulate count of an operation from the static shapes of its operands.
+fn estimate_macs(
+    code: OpCode,
+    options: &OpOptions,
+    inputs: &[Option<Vec<usize>>],
+    outputs: &[Option<Vec<usize>>],
+) -> Option<u64> {
+    let product = |dims: &[usize]| dims.iter().map(|&dim| dim as u64).product::<u64>();
+    let input = |index: usize| -> Option<&[usize]> { inputs.get(index)?.as_deref() };
+    let output_elements = product(outputs.first()?.as_deref()?);
+    match (code, options) {
+        // Filter [output_channels, height, width, input_channels].
+        (OpCode::TflConv2d, _) => match input(1)? {
+            &[_, height, width, input_channels] => {
+                Some(output_elements * product(&[height, width, input_channels]))
+            }
+            _ => None,
+        },
+        // Filter [1, height, width, channels].
+        (OpCode::TflDepthwiseConv2d, _) => match input(1)? {
+            &[_, height, width, _] => Some(output_elements * product(&[height, width])),
+            _ => None,
+        },
+        // Filter [depth, height, width, input_channels, output_channels].
+        (OpCode::TflConv3d, _) => match input(1)? {
+            &[depth, height, width, input_channels, _] => {
+                Some(output_elements * product(&[depth, height, width, input_channels]))
+            }
+            _ => None,
+        },
+        // Inputs are the output shape, the filter [output_channels, height, width,
+        // input_channels] and the input, every input element is scattered through the filter.
+        (OpCode::TflTransposeConv, _) => match input(1)? {
+            &[output_channels, height, width, _] => {
+                Some(product(input(2)?) * product(&[output_channels, height, width]))
+            }
+            _ => None,
+        },
+        // Weights [output_units, input_units].
+        (OpCode::TflFullyConnected, _) => Some(output_elements * *input(1)?.last()? as u64),
+        (OpCode::TflBatchMatmul, OpOptions::BatchMatmul(o)) => {
+            let lhs = input(0)?;
+            let depth = if o.adj_x {
+                lhs.len().checked_sub(2)?
+            } else {
+                lhs.len().checked_sub(1)?
+            };
+            Some(output_elements * lhs[depth] as u64)
+        }
+        (
+            OpCode::TflAveragePool2d | OpCode::TflMaxPool2d | OpCode::TflL2Pool2d,
+            OpOptions::AveragePool2d(o) | OpOptions::MaxPool2d(o) | OpOptions::L2Pool2d(o),
+        ) => Some(output_elements * (o.filter_height as u64) * (o.filter_width as u64)),
+        _ => None,
+    }
+}
+
+/// Structural differences between two models, see [`diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelDiff {
+    pub added_signatures: Vec<String>,
+    pub removed_signatures: Vec<String>,
+    /// Signatures of both models with different inputs or outputs.
+    pub changed_signatures: Vec<SignatureDiff>,
+    /// Operation codes with a different number of operations.
+    pub op_count_changes: Vec<OpCountChange>,
+    /// Same-named tensors of the same subgraph with different quantization or weights.
+    pub changed_tensors: Vec<TensorChange>,
+}
+
+impl ModelDiff {
+    /// Returns `true` if the models have the same signatures with the same input and output types.
+    pub fn is_interface_unchanged(&self) -> bool {
+        self.added_signatures.is_empty()
+            && self.removed_signatures.is_empty()
+            && self.changed_signatures.is_empty()
+    }
+
+    /// Returns `true` if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self.is_interface_unchanged()
+            && self.op_count_changes.is_empty()
+            && self.changed_tensors.is_empty()
+    }
+}
+
+/// Differences between the inputs and outputs of a signature.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SignatureDiff {
+    pub key: String,
+    pub added_inputs: Vec<String>,
+    pub removed_inputs: Vec<String>,
+    pub changed_inputs: Vec<IoTensorChange>,
+    pub added_outputs: Vec<String>,
+    pub removed_outputs: Vec<String>,
+    pub changed_outputs: Vec<IoTensorChange>,
+}
+
+/// A signature input or output with a different element type or shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoTensorChange {
+    pub name: String,
+    pub old: IoTensorType,
+    pub new: IoTensorType,
+}
+
+/// The type of a signature input or output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoTensorType {
+    pub element_type: ElementType,
+    /// Dimensions of a ranked tensor, dynamic dimensions are `None`.
+    pub shape: Option<Vec<Option<usize>>>,
+}
+
+/// An operation code with a different number of operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpCountChange {
+    pub code: OpCode,
+    pub old_count: usize,
+    pub new_count: usize,
+}
+
+/// A tensor with different quantization or weights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorChange {
+    pub subgraph_index: usize,
+    pub name: String,
+    pub old_quantization: Quantization,
+    pub new_quantization: Quantization,
+    /// FNV-1a checksum of the constant weights, `None` for tensors without weights.
+    pub old_weights_checksum: Option<u64>,
+    pub new_weights_checksum: Option<u64>,
+}
+
+/// Compares the signatures, operations and tensors of two models.
+///
+/// Signatures are matched by key, inputs and outputs by name, tensors by subgraph index and name.
+pub fn diff(old: &Model, new: &Model) -> Result<ModelDiff, Error> {
+    let old_signatures = signature_types(old)?;
+    let new_signatures = signature_types(new)?;
+    let mut result = ModelDiff::default();
+    (result.added_signatures, result.removed_signatures) = diff_keys(
+        old_signatures.iter().map(|s| &s.0),
+        new_signatures.iter().map(|s| &s.0),
+    );
+    for (key, old_inputs, old_outputs) in &old_signatures {
+        let Some((_, new_inputs, new_outputs)) = new_signatures.iter().find(|s| &s.0 == key) else {
+            continue;
+        };
+        let (added_inputs, removed_inputs, changed_inputs) = diff_io(old_inputs, new_inputs);
+        let (added_outputs, removed_outputs, changed_outputs) = diff_io(old_outputs, new_outputs);
+        let signature_diff = SignatureDiff {
+            key: key.clone(),
+            added_inputs,
+            removed_inputs,
+            changed_inputs,
+            added_outputs,
+            removed_outputs,
+            changed_outputs,
+        };
+        if signature_diff
+            != (SignatureDiff {
+                key: key.clone(),
+                ..Default::default()
+            })
+        {
+            result.changed_signatures.push(signature_diff);
+        }
+    }
+    result.op_count_changes = diff_histograms(&op_histogram(old)?, &op_histogram(new)?);
+    let new_tensors = tensor_infos(new)?;
+    for (key, (old_quantization, old_weights_checksum)) in tensor_infos(old)? {
+        let Some((new_quantization, new_weights_checksum)) = new_tensors.get(&key) else {
+            continue;
+        };
+        if &old_quantization != new_quantization || &old_weights_checksum != new_weights_checksum {
+            result.changed_tensors.push(TensorChange {
+                subgraph_index: key.0,
+                name: key.1,
+                old_quantization,
+                new_quantization: new_quantization.clone(),
+                old_weights_checksum,
+                new_weights_checksum: *new_weights_checksum,
+            });
+        }
+    }
+    result
+        .changed_tensors
+        .sort_by(|a, b| (a.subgraph_index, &a.name).cmp(&(b.subgraph_index, &b.name)));
+    Ok(result)
+}
+
+type IoTensors = Vec<(String, IoTensorType)>;
+// Quantization and weights checksum by subgraph index and tensor name.
+type TensorInfos = HashMap<(usize, String), (Quantization, Option<u64>)>;
+
+fn io_tensor_type(tensor: &Tensor<'_>) -> Result<IoTensorType, Error> {
+    let shape = match tensor.tensor_type() {
+        Ok(tensor_type) => Some(tensor_type.shape.dims().to_vec()),
+        Err(e) if e.cause() == ErrorCause::UnrankedTensor => None,
+        Err(e) => return Err(e),
+    };
+    Ok(IoTensorType {
+        element_type: tensor.element_type()?,
+        shape,
+    })
+}
+
+// Returns the key, input types and output types of every signature.
+fn signature_types(model: &Model) -> Result<Vec<(String, IoTensors, IoTensors)>, Error> {
+    let io_types = |signature: &Signature<'_>, inputs: bool| -> Result<IoTensors, Error> {
+        let names = if inputs {
+            signature.input_names()?
+        } else {
+            signature.output_names()?
+        };
+        names
+            .map(|name| {
+                let name = name?;
+                let tensor = if inputs {
+                    signature.input_tensor(name)?
+                } else {
+                    signature.output_tensor(name)?
+                };
+                Ok((name.to_string(), io_tensor_type(&tensor)?))
+            })
+            .collect()
+    };
+    model
+        .signatures()?
+        .map(|signature| {
+            let signature = signature?;
+            Ok((
+                signature.key()?.to_string(),
+                io_types(&signature, true)?,
+                io_types(&signature, false)?,
+            ))
+        })
+        .collect()
+}
+
+// Returns the number of operations of every code in the order the codes first appear.
+fn op_histogram(model: &Model) -> Result<Vec<(OpCode, usize)>, Error> {
+    let mut histogram: Vec<(OpCode, usize)> = Vec::new();
+    for subgraph in model.subgraphs()? {
+        for op in subgraph?.ops()? {
+            let code = op?.code()?;
+            match histogram.iter_mut().find(|(c, _)| *c == code) {
+                Some((_, count)) => *count += 1,
+                None => histogram.push((code, 1)),
+            }
+        }
+    }
+    Ok(histogram)
+}
+
+// Returns the quantization and weights checksum of the named operands of every subgraph.
+fn tensor_infos(model: &Model) -> Result<TensorInfos, Error> {
+    let mut infos = HashMap::new();
+    for (subgraph_index, subgraph) in model.subgraphs()?.enumerate() {
+        for op in subgraph?.ops()? {
+            let op = op?;
+            for tensor in op.inputs()?.chain(op.outputs()?) {
+                let tensor = tensor?;
+                let key = (subgraph_index, tensor.name()?.to_string());
+                if key.1.is_empty() || infos.contains_key(&key) {
+                    continue;
+                }
+                let weights = tensor.weights()?;
+                let checksum = (!weights.is_empty()).then(|| fnv1a(weights.bytes()));
+                infos.insert(key, (tensor.quantization()?, checksum));
+            }
+        }
+    }
+    Ok(infos)
+}
+
+// Returns the keys only in `new` and the keys only in `old`.
+fn diff_keys<'k>(
+    old: impl Iterator<Item = &'k String> + Clone,
+    new: impl Iterator<Item = &'k String> + Clone,
+) -> (Vec<String>, Vec<String>) {
+    let added = new
+        .clone()
+        .filter(|key| !old.clone().any(|k| k == *key))
+        .cloned()
+        .collect();
+    let removed = old
+        .filter(|key| !new.clone().any(|k| k == *key))
+        .cloned()
+        .collect();
+    (added, removed)
+}
+
+fn diff_io(old: &IoTensors, new: &IoTensors) -> (Vec<String>, Vec<String>, Vec<IoTensorChange>) {
+    let (added, removed) = diff_keys(old.iter().map(|t| &t.0), new.iter().map(|t| &t.0));
+    let changed = old
+        .iter()
+        .filter_map(|(name, old_type)| {
+            let (_, new_type) = new.iter().find(|t| &t.0 == name)?;
+            (old_type != new_type).then(|| IoTensorChange {
+                name: name.clone(),
+                old: old_type.clone(),
+                new: new_type.clone(),
+            })
+        })
+        .collect();
+    (added, removed, changed)
+}
+
+fn diff_histograms(old: &[(OpCode, usize)], new: &[(OpCode, usize)]) -> Vec<OpCountChange> {
+    let count = |histogram: &[(OpCode, usize)], code: OpCode| {
+        histogram
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map_or(0, |(_, count)| *count)
+    };
+    let mut changes: Vec<OpCountChange> = Vec::new();
+    for &(code, _) in old.iter().chain(new) {
+        let change = OpCountChange {
+            code,
+            old_count: count(old, code),
+            new_count: count(new, code),
+        };
+        if change.old_count != change.new_count && !changes.iter().any(|c| c.code == code) {
+            changes.push(change);
+        }
+    }
+    changes
+}
+
+// 64-bit FNV-1a, stable across platforms and releases unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op_options::{BatchMatmulOptions, FusedActivation, Padding, Pool2dOptions};
+
+    #[test]
+    fn test_estimate_macs() {
+        let conv = estimate_macs(
+            OpCode::TflConv2d,
+            &OpOptions::None,
+            &[
+                Some(vec![1, 8, 8, 3]),
+                Some(vec![16, 3, 3, 3]),
+                Some(vec![16]),
+            ],
+            &[Some(vec![1, 8, 8, 16])],
+        );
+        assert_eq!(conv, Some(8 * 8 * 16 * 3 * 3 * 3));
+
+        let depthwise = estimate_macs(
+            OpCode::TflDepthwiseConv2d,
+            &OpOptions::None,
+            &[Some(vec![1, 8, 8, 4]), Some(vec![1, 3, 3, 4])],
+            &[Some(vec![1, 8, 8, 4])],
+        );
+        assert_eq!(depthwise, Some(8 * 8 * 4 * 3 * 3));
+
+        let fully_connected = estimate_macs(
+            OpCode::TflFullyConnected,
+            &OpOptions::None,
+            &[Some(vec![2, 10]), Some(vec![5, 10])],
+            &[Some(vec![2, 5])],
+        );
+        assert_eq!(fully_connected, Some(2 * 5 * 10));
+
+        let batch_matmul = estimate_macs(
+            OpCode::TflBatchMatmul,
+            &OpOptions::BatchMatmul(BatchMatmulOptions {
+                adj_x: true,
+                adj_y: false,
+                asymmetric_quantize_input: false,
+            }),
+            &[Some(vec![4, 7, 2]), Some(vec![4, 7, 3])],
+            &[Some(vec![4, 2, 3])],
+        );
+        assert_eq!(batch_matmul, Some(4 * 2 * 3 * 7));
+
+        let pool = estimate_macs(
+            OpCode::TflMaxPool2d,
+            &OpOptions::MaxPool2d(Pool2dOptions {
+                padding: Padding::Valid,
+                stride_h: 2,
+                stride_w: 2,
+                filter_height: 2,
+                filter_width: 2,
+                fused_activation: FusedActivation::None,
+            }),
+            &[Some(vec![1, 4, 4, 1])],
+            &[Some(vec![1, 2, 2, 1])],
+        );
+        assert_eq!(pool, Some(4 * 2 * 2));
+
+        let dynamic = estimate_macs(
+            OpCode::TflFullyConnected,
+            &OpOptions::None,
+            &[None, Some(vec![5, 10])],
+            &[None],
+        );
+        assert_eq!(dynamic, None);
+        assert_eq!(
+            estimate_macs(OpCode::TflAdd, &OpOptions::None, &[], &[Some(vec![1])]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_diff_io() {
+        let f32_type = |dims: &[usize]| IoTensorType {
+            element_type: ElementType::Float32,
+            shape: Some(dims.iter().map(|&dim| Some(dim)).collect()),
+        };
+        let old = vec![
+            ("a".to_string(), f32_type(&[1, 2])),
+            ("b".to_string(), f32_type(&[3])),
+        ];
+        let new = vec![
+            ("a".to_string(), f32_type(&[1, 4])),
+            ("c".to_string(), f32_type(&[3])),
+        ];
+        let (added, removed, changed) = diff_io(&old, &new);
+        assert_eq!(added, vec!["c".to_string()]);
+        assert_eq!(removed, vec!["b".to_string()]);
+        assert_eq!(
+            changed,
+            vec![IoTensorChange {
+                name: "a".to_string(),
+                old: f32_type(&[1, 2]),
+                new: f32_type(&[1, 4])
+            }]
+        );
+        assert_eq!(diff_io(&old, &old), (vec![], vec![], vec![]));
+    }
+
+    #[test]
+    fn test_diff_histograms() {
+        let old = [(OpCode::TflAdd, 2), (OpCode::TflConv2d, 1)];
+        let new = [
+            (OpCode::TflConv2d, 1),
+            (OpCode::TflAdd, 1),
+            (OpCode::TflMul, 3),
+        ];
+        assert_eq!(
+            diff_histograms(&old, &new),
+            vec![
+                OpCountChange {
+                    code: OpCode::TflAdd,
+                    old_count: 2,
+                    new_count: 1
+                },
+                OpCountChange {
+                    code: OpCode::TflMul,
+                    old_count: 0,
+                    new_count: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -4,14 +4,49 @@ use crate::bindings::*;
 use crate::call_check_status;
 use crate::environment::Environment;
 use crate::error::{Error, ErrorCause};
-use crate::model::{Model, Tensor};
-use crate::tensor_buffer::{TensorBuffer, TensorBufferRequirements};
+use crate::model::{Model, Signature, Tensor};
+use crate::tensor_buffer::{acquire_all, TensorBuffer, TensorBufferRequirements, TensorBufferType};
+use std::marker::PhantomData;
 
 /// Options for compiling a model.
 pub struct Options {
     raw_options: LiteRtOptions,
 }
 
+/// Selects which of the tensor buffer types supported by a compiled model is used for new
+/// tensor buffers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum BufferPreference {
+    /// The first supported type, i.e. the type preferred by the compiled model.
+    #[default]
+    FirstSupported,
+    /// The first type of the list that is supported, in order of preference.
+    Ranked(Vec<TensorBufferType>),
+}
+
+impl BufferPreference {
+    fn select(&self, supported_types: &[TensorBufferType]) -> Result<TensorBufferType, Error> {
+        let selected = match self {
+            Self::FirstSupported => supported_types.first(),
+            Self::Ranked(types) => types.iter().find(|t| supported_types.contains(t)),
+        };
+        if let Some(&buffer_type) = selected {
+            return Ok(buffer_type);
+        }
+        Err(if supported_types.is_empty() {
+            Error::new(
+                ErrorCause::InputDoesntSupportAnyTensorBufferTypes,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )
+        } else {
+            Error::new(
+                ErrorCause::UnsupportedTensorBufferType,
+                LiteRtStatus_kLiteRtStatusErrorUnsupported,
+            )
+        })
+    }
+}
+
 /// Hardware accelerators that can be used for inference.
 pub enum LiteRtHwAccelerator {
     None,
@@ -72,15 +107,33 @@ impl Drop for Options {
 }
 
 /// A compiled model that can be used to run inference.
-pub struct CompiledModel {
+///
+/// LiteRT runs the compiled model directly on the flatbuffer of the model, so the compiled model
+/// borrows the [`Model`] it's created from and can't outlive it:
+///
+/// ```compile_fail
+/// # fn example(
+/// #     environment: &litert::Environment,
+/// #     options: &litert::Options,
+/// #     data: &[u8],
+/// # ) -> Result<(), litert::Error> {
+/// let compiled_model = {
+///     let model = litert::Model::create_model_from_buffer(data)?;
+///     litert::CompiledModel::create(environment, &model, options)?
+/// };
+/// # Ok(())
+/// # }
+/// ```
+pub struct CompiledModel<'m> {
     pub(crate) raw_compiled_model: LiteRtCompiledModel,
+    _phantom: PhantomData<&'m Model<'m>>,
 }
 
-impl CompiledModel {
+impl<'m> CompiledModel<'m> {
     /// Creates a new compiled model.
     pub fn create(
         environment: &Environment,
-        model: &Model,
+        model: &'m Model<'_>,
         options: &Options,
     ) -> Result<Self, Error> {
         let mut raw_compiled_model_ptr: *mut LiteRtCompiledModelT = std::ptr::null_mut();
@@ -98,10 +151,12 @@ impl CompiledModel {
         );
         Ok(CompiledModel {
             raw_compiled_model: raw_compiled_model_ptr,
+            _phantom: PhantomData,
         })
     }
 
-    fn input_buffer_requirements(
+    /// Returns the requirements for the tensor buffer of an input of the specified signature.
+    pub fn input_buffer_requirements(
         &self,
         signature_index: LiteRtParamIndex,
         input_index: LiteRtParamIndex,
@@ -122,7 +177,8 @@ impl CompiledModel {
         Ok(TensorBufferRequirements::new(requirements_ptr))
     }
 
-    fn output_buffer_requirements(
+    /// Returns the requirements for the tensor buffer of an output of the specified signature.
+    pub fn output_buffer_requirements(
         &self,
         signature_index: LiteRtParamIndex,
         output_index: LiteRtParamIndex,
@@ -149,15 +205,36 @@ impl CompiledModel {
         environment: &Environment,
         model: &Model,
         signature_index: LiteRtParamIndex,
+    ) -> Result<Vec<TensorBuffer<'_>>, Error> {
+        self.create_input_tensor_buffers_with(
+            environment,
+            model,
+            signature_index,
+            &BufferPreference::default(),
+        )
+    }
+
+    /// Creates a set of input tensor buffers for the specified signature, with buffer types
+    /// selected by `preference`.
+    pub fn create_input_tensor_buffers_with(
+        &self,
+        environment: &Environment,
+        model: &Model,
+        signature_index: LiteRtParamIndex,
+        preference: &BufferPreference,
     ) -> Result<Vec<TensorBuffer<'_>>, Error> {
         let signature = model.signature(signature_index)?;
-        let subgraph = signature.subgraph()?;
-        let mut result = Vec::with_capacity(signature.num_inputs()?);
-        for (i, input_name) in signature.input_names()?.enumerate() {
+        let num_inputs = signature.num_inputs()?;
+        let mut result = Vec::with_capacity(num_inputs);
+        for i in 0..num_inputs {
             let input_requirements = self.input_buffer_requirements(signature_index, i)?;
-            let tensor = subgraph.input_tensor_by_name(input_name?)?;
-            let buffer =
-                CompiledModel::create_buffer_impl(environment, &input_requirements, &tensor)?;
+            let tensor = signature.input_tensor_by_index(i)?;
+            let buffer = CompiledModel::create_buffer_impl(
+                environment,
+                &input_requirements,
+                &tensor,
+                preference,
+            )?;
             result.push(buffer);
         }
         Ok(result)
@@ -169,52 +246,128 @@ impl CompiledModel {
         environment: &Environment,
         model: &Model,
         signature_index: LiteRtParamIndex,
+    ) -> Result<Vec<TensorBuffer<'_>>, Error> {
+        self.create_output_tensor_buffers_with(
+            environment,
+            model,
+            signature_index,
+            &BufferPreference::default(),
+        )
+    }
+
+    /// Creates a set of output tensor buffers for the specified signature, with buffer types
+    /// selected by `preference`.
+    pub fn create_output_tensor_buffers_with(
+        &self,
+        environment: &Environment,
+        model: &Model,
+        signature_index: LiteRtParamIndex,
+        preference: &BufferPreference,
     ) -> Result<Vec<TensorBuffer<'_>>, Error> {
         let signature = model.signature(signature_index)?;
-        let subgraph = signature.subgraph()?;
-        let mut result = Vec::with_capacity(signature.num_outputs()?);
-        for (i, output_name) in signature.output_names()?.enumerate() {
+        let num_outputs = signature.num_outputs()?;
+        let mut result = Vec::with_capacity(num_outputs);
+        for i in 0..num_outputs {
             let output_requirements = self.output_buffer_requirements(signature_index, i)?;
-            let tensor = subgraph.output_tensor_by_name(output_name?)?;
-            let buffer =
-                CompiledModel::create_buffer_impl(environment, &output_requirements, &tensor)?;
+            let tensor = signature.output_tensor_by_index(i)?;
+            let buffer = CompiledModel::create_buffer_impl(
+                environment,
+                &output_requirements,
+                &tensor,
+                preference,
+            )?;
             result.push(buffer);
         }
         Ok(result)
     }
 
+    /// Creates a tensor buffer of the given type for the input with the given name of
+    /// `signature`.
+    ///
+    /// The buffer requirements of a compiled model are indexed by position, so the name is looked
+    /// up among the input names of `signature`.
+    ///
+    /// Returns an error if the compiled model doesn't support `buffer_type` for the input.
+    pub fn create_input_buffer(
+        &self,
+        environment: &Environment,
+        signature: &Signature<'_>,
+        name: &str,
+        buffer_type: TensorBufferType,
+    ) -> Result<TensorBuffer<'_>, Error> {
+        let Some(index) = position_of_name(signature.input_names()?, name)? else {
+            return Err(Error::new(
+                ErrorCause::SignatureInputNotFound,
+                LiteRtStatus_kLiteRtStatusErrorNotFound,
+            ));
+        };
+        CompiledModel::create_buffer_impl(
+            environment,
+            &self.input_buffer_requirements(signature.index(), index)?,
+            &signature.input_tensor_by_index(index)?,
+            &BufferPreference::Ranked(vec![buffer_type]),
+        )
+    }
+
+    /// Creates a tensor buffer of the given type for the output with the given name of
+    /// `signature`.
+    ///
+    /// The buffer requirements of a compiled model are indexed by position, so the name is looked
+    /// up among the output names of `signature`.
+    ///
+    /// Returns an error if the compiled model doesn't support `buffer_type` for the output.
+    pub fn create_output_buffer(
+        &self,
+        environment: &Environment,
+        signature: &Signature<'_>,
+        name: &str,
+        buffer_type: TensorBufferType,
+    ) -> Result<TensorBuffer<'_>, Error> {
+        let Some(index) = position_of_name(signature.output_names()?, name)? else {
+            return Err(Error::new(
+                ErrorCause::SignatureOutputNotFound,
+                LiteRtStatus_kLiteRtStatusErrorNotFound,
+            ));
+        };
+        CompiledModel::create_buffer_impl(
+            environment,
+            &self.output_buffer_requirements(signature.index(), index)?,
+            &signature.output_tensor_by_index(index)?,
+            &BufferPreference::Ranked(vec![buffer_type]),
+        )
+    }
+
     fn create_buffer_impl<'a>(
         environment: &Environment,
         requirements: &TensorBufferRequirements,
         tensor: &Tensor,
+        preference: &BufferPreference,
     ) -> Result<TensorBuffer<'a>, Error> {
-        let supported_types = requirements.supported_types()?;
-        // For simplicity we just pick the first supported tensor buffer type.
-        let Some(buffer_type) = supported_types.get(0) else {
-            return Err(Error::new(
-                ErrorCause::InputDoesntSupportAnyTensorBufferTypes,
-                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
-            ));
-        };
+        let buffer_type = preference.select(&requirements.supported_types()?)?;
         let tensor_type = tensor.ranked_tensor_type()?;
         let element_type = tensor.element_type()?;
         let buffer_size = requirements.buffer_size()?;
         TensorBuffer::new(
             environment,
             &tensor_type,
-            buffer_type,
+            &buffer_type,
             buffer_size,
             element_type,
         )
     }
 
     /// Runs inference on the compiled model.
+    ///
+    /// The output buffers are borrowed mutably, so none of them can be mapped while the model
+    /// writes to them.
     pub fn run(
         &self,
         signature_index: LiteRtParamIndex,
-        input: &Vec<TensorBuffer<'_>>,
-        output: &Vec<TensorBuffer<'_>>,
+        input: &[TensorBuffer<'_>],
+        output: &mut [TensorBuffer<'_>],
     ) -> Result<(), Error> {
+        // Other handles of the buffers may still have them locked.
+        let _in_use = acquire_all(input.iter().chain(output.iter()))?;
         let mut input_ptrs: Vec<_> = input
             .iter()
             .map(|tensor| tensor.raw_tensor_buffer)
@@ -241,3 +394,46 @@ impl CompiledModel {
         Ok(())
     }
 }
+
+fn position_of_name<'a>(
+    names: impl Iterator<Item = Result<&'a str, Error>>,
+    name: &str,
+) -> Result<Option<LiteRtParamIndex>, Error> {
+    for (index, n) in names.enumerate() {
+        if n? == name {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_preference_select() {
+        let supported = [TensorBufferType::Ahwb, TensorBufferType::HostMemory];
+        assert_eq!(
+            BufferPreference::FirstSupported.select(&supported),
+            Ok(TensorBufferType::Ahwb)
+        );
+        let preference =
+            BufferPreference::Ranked(vec![TensorBufferType::DmaBuf, TensorBufferType::HostMemory]);
+        assert_eq!(
+            preference.select(&supported),
+            Ok(TensorBufferType::HostMemory)
+        );
+        let preference = BufferPreference::Ranked(vec![TensorBufferType::GlBuffer]);
+        assert_eq!(
+            preference.select(&supported).map_err(|e| e.cause()),
+            Err(ErrorCause::UnsupportedTensorBufferType)
+        );
+        assert_eq!(
+            BufferPreference::FirstSupported
+                .select(&[])
+                .map_err(|e| e.cause()),
+            Err(ErrorCause::InputDoesntSupportAnyTensorBufferTypes)
+        );
+    }
+}
@@ -90,7 +90,10 @@ impl EnvironmentBuilder {
     }
 
     pub fn new() -> Self {
-        Self { options: Vec::new(), cstring_storage: Vec::new() }
+        Self {
+            options: Vec::new(),
+            cstring_storage: Vec::new(),
+        }
     }
 
     fn build_lite_rt_any<T: 'static>(&mut self, value: T) -> Result<LiteRtAny, Error> {
@@ -139,11 +142,14 @@ impl EnvironmentBuilder {
         } else if TypeId::of::<&str>() == type_id {
             // SAFETY: unwrap is safe here, because we checked that type_id is of<&str>.
             let str_value = *any_value.downcast_ref::<&str>().unwrap();
-            self.cstring_storage.push(CString::new(str_value).expect("Failed to create CString"));
+            self.cstring_storage
+                .push(CString::new(str_value).expect("Failed to create CString"));
             let cstr_ptr = self.cstring_storage.last().unwrap().as_ptr();
             Ok(LiteRtAny {
                 type_: LiteRtAnyType_kLiteRtAnyTypeString,
-                __bindgen_anon_1: LiteRtAny__bindgen_ty_1 { str_value: cstr_ptr },
+                __bindgen_anon_1: LiteRtAny__bindgen_ty_1 {
+                    str_value: cstr_ptr,
+                },
             })
         } else {
             Err(Error::new(
@@ -155,7 +161,10 @@ impl EnvironmentBuilder {
 
     pub fn add_option<T: 'static>(mut self, tag: OptionTag, value: T) -> Result<Self, Error> {
         let val = self.build_lite_rt_any(value)?;
-        self.options.push(LiteRtEnvOption { tag: tag.to_c_enum(), value: val });
+        self.options.push(LiteRtEnvOption {
+            tag: tag.to_c_enum(),
+            value: val,
+        });
         Ok(self)
     }
 }
@@ -176,7 +185,10 @@ impl Environment {
             },
             ErrorCause::CreateEnvironment
         );
-        Ok(Self { raw_environment: raw_environment_ptr, cstring_storage: builder.cstring_storage })
+        Ok(Self {
+            raw_environment: raw_environment_ptr,
+            cstring_storage: builder.cstring_storage,
+        })
     }
 }
 
@@ -14,6 +14,9 @@ pub enum ErrorCause {
     GetCompiledModelInputBufferRequirements,
     GetCompiledModelOutputBufferRequirements,
     InputDoesntSupportAnyTensorBufferTypes,
+    UnsupportedTensorBufferType,
+    SignatureInputNotFound,
+    SignatureOutputNotFound,
     RunCompiledModel,
     // environment
     NotSupportedLiteRtAnyType,
@@ -30,6 +33,7 @@ pub enum ErrorCause {
     GetUnrankedTensorType,
     GetRankedTensorType,
     InvalidTensorTypeId,
+    UnrankedTensor,
     GetTensorName,
     GetNumSubgraphInputs,
     GetNumSubgraphOutputs,
@@ -42,20 +46,117 @@ pub enum ErrorCause {
     GetNumModelSubgraphs,
     GetNumModelSignatures,
     GetModelSignature,
+    GetModelSubgraph,
+    GetMainModelSubgraphIndex,
+    GetNumSubgraphOps,
+    GetSubgraphOp,
+    GetOpCode,
+    GetCustomCode,
+    InvalidOpCodeEnumValue,
+    GetNumOpInputs,
+    GetOpInput,
+    GetNumOpOutputs,
+    GetOpOutput,
+    GetTensorWeights,
+    GetWeightsBytes,
+    GetWeightsBufferId,
+    WeightsElementTypeMismatch,
+    MisalignedWeights,
+    GetQuantizationTypeId,
+    GetPerTensorQuantization,
+    GetPerChannelQuantization,
+    UnsupportedQuantizationType,
+    GetTensorDefiningOp,
+    GetNumTensorUses,
+    GetTensorUse,
+    SubgraphHasCycle,
+    InvalidMetadataKey,
+    GetModelMetadata,
+    AddModelMetadata,
+    InvalidSignatureKey,
+    SerializeModel,
+    WriteModelFile,
+    InvalidSignatureTensorName,
+    GetSignatureInputTensor,
+    GetSignatureOutputTensor,
+    GetDefaultSignatureKey,
+    SignatureNotFound,
+    // op_options
+    InvalidPaddingValue,
+    InvalidFusedActivationValue,
+    InvalidMirrorPadModeValue,
+    InvalidWeightsFormatValue,
+    GetAddOptions,
+    GetSubOptions,
+    GetMulOptions,
+    GetDivOptions,
+    GetBatchMatmulOptions,
+    GetConcatenationOptions,
+    GetFullyConnectedOptions,
+    GetSoftmaxOptions,
+    GetStridedSliceOptions,
+    GetReshapeOptions,
+    GetSumOptions,
+    GetMeanOptions,
+    GetReduceMaxOptions,
+    GetReduceMinOptions,
+    GetReduceAnyOptions,
+    GetReduceAllOptions,
+    GetPackOptions,
+    GetUnpackOptions,
+    GetGatherOptions,
+    GetSplitOptions,
+    GetConv2dOptions,
+    GetConv3dOptions,
+    GetDepthwiseConv2dOptions,
+    GetTransposeConvOptions,
+    GetAveragePool2dOptions,
+    GetMaxPool2dOptions,
+    GetL2Pool2dOptions,
+    GetResizeBilinearOptions,
+    GetResizeNearestNeighborOptions,
+    GetLeakyReluOptions,
+    GetDepthToSpaceOptions,
+    GetSpaceToDepthOptions,
+    GetCumsumOptions,
+    GetGeluOptions,
+    GetMirrorPadOptions,
+    GetSqueezeOptions,
+    GetCompositeOptions,
     //tensor_buffer
     GetTensorBufferRequirementsBufferSize,
     GetNumTensorBufferRequirementsSupportedBufferTypes,
     GetTensorBufferRequirementsSupportedTensorBufferType,
+    GetTensorBufferRequirementsAlignment,
+    GetTensorBufferRequirementsStrides,
+    JoinTensorBufferRequirements,
     InvalidElementTypeEnumValue,
     InvalidTensorBufferTypeEnumValue,
     CreateManagedTensorBuffer,
+    CreateManagedTensorBufferFromRequirements,
+    CreateTensorBufferFromHostMemory,
+    IncompatibleHostMemoryType,
+    HostMemoryTooSmall,
+    MisalignedHostMemory,
+    InvalidHostMemoryLayout,
+    InvalidTensorType,
+    InvalidTensorRank,
     LockTensorBufferRead,
     LockTensorBufferWrite,
+    LockTensorBufferReadWrite,
+    MisalignedTensorBufferData,
     GetTensorBufferPackedSize,
     IncompatibleWriteType,
     TensorBufferTooSmall,
     IncompatibleReadType,
     ReadBufferTooSmall,
+    GetTensorBufferTensorType,
+    GetTensorBufferType,
+    GetTensorBufferSize,
+    GetTensorBufferOffset,
+    ClearTensorBuffer,
+    TensorBufferAlreadyLocked,
+    InvalidQuantizationParameters,
     // util
     InvalidStringEncoding,
 }
@@ -84,7 +185,11 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Error: {:?}", self.cause)?;
         let status_description = self.litert_status_description();
-        write!(f, " LiteRtStatus: {:?} [{}] ", self.litert_status, status_description)
+        write!(
+            f,
+            " LiteRtStatus: {:?} [{}] ",
+            self.litert_status, status_description
+        )
     }
 }
 
@@ -98,7 +203,10 @@ impl std::error::Error for Error {}
 
 impl Error {
     pub(crate) fn new(cause: ErrorCause, status: LiteRtStatus) -> Self {
-        Error { cause, litert_status: status }
+        Error {
+            cause,
+            litert_status: status,
+        }
     }
 
     /// Returns the reason of the error from the binding code.
@@ -119,7 +227,10 @@ impl Error {
             if description.is_null() {
                 "???".to_string()
             } else {
-                CStr::from_ptr(description).to_str().unwrap_or("???").to_string()
+                CStr::from_ptr(description)
+                    .to_str()
+                    .unwrap_or("???")
+                    .to_string()
             }
         };
         status_description
@@ -30,11 +30,11 @@
 //! // 5. Create input and output tensor buffers.
 //! // We are using the first signature (index 0).
 //! let signature_index = 0;
-//! let input_buffers = compiled_model
+//! let mut input_buffers = compiled_model
 //!     .create_input_tensor_buffers(&environment, &model, signature_index)
 //!     .expect("Failed to create input buffers");
 //!
-//! let output_buffers = compiled_model
+//! let mut output_buffers = compiled_model
 //!     .create_output_tensor_buffers(&environment, &model, signature_index)
 //!     .expect("Failed to create output buffers");
 //!
@@ -44,7 +44,7 @@
 //! input_buffers[0].write(&input_data).expect("Failed to write to input buffer");
 //!
 //! // 7. Run inference.
-//! compiled_model.run(signature_index, &input_buffers, &output_buffers)
+//! compiled_model.run(signature_index, &input_buffers, &mut output_buffers)
 //!     .expect("Failed to run model");
 //!
 //! // 8. (Optional) Read results from output buffers.
@@ -54,18 +54,24 @@
 //! println!("Inference successful. Output data: {:?}", output_data);
 //! ```
 
+pub mod analysis;
 mod bindings;
 pub mod compiled_model;
 pub mod environment;
 pub mod error;
 mod helper_funs;
 pub mod model;
+pub mod op_code;
+pub mod op_options;
+pub mod summary;
 pub mod tensor_buffer;
+pub mod viz;
 #[macro_use]
 mod macros;
 
 // Make some types available to the user.
 pub use bindings::LiteRtStatus;
+pub use compiled_model::BufferPreference;
 pub use compiled_model::CompiledModel;
 pub use compiled_model::LiteRtHwAccelerator;
 pub use compiled_model::Options;
@@ -74,7 +80,22 @@ pub use environment::EnvironmentBuilder;
 pub use error::Error;
 pub use error::ErrorCause;
 pub use model::Model;
+pub use model::Quantization;
+pub use model::SerializationOptions;
+pub use model::Weights;
+pub use model::MODEL_BUFFER_ALIGNMENT;
+pub use op_code::OpCode;
+pub use op_options::OpOptions;
+pub use summary::ModelSummary;
+pub use tensor_buffer::AlignedHostMemory;
 pub use tensor_buffer::ElementType;
+pub use tensor_buffer::OwnedHostMemory;
+pub use tensor_buffer::PackedInt4;
+pub use tensor_buffer::ReadGuard;
+pub use tensor_buffer::Shape;
 pub use tensor_buffer::TensorBuffer;
 pub use tensor_buffer::TensorBufferRequirements;
 pub use tensor_buffer::TensorBufferType;
+pub use tensor_buffer::TensorElement;
+pub use tensor_buffer::TensorType;
+pub use tensor_buffer::WriteGuard;
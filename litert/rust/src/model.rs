@@ -4,20 +4,129 @@ use crate::bindings::*;
 use crate::call_check_status;
 use crate::error::{Error, ErrorCause};
 use crate::helper_funs::c_str_to_str;
+use crate::op_code::OpCode;
+use crate::tensor_buffer::TensorType;
 use crate::ElementType;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::ffi::{c_char, c_void, CString};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// `Model` is a wrapper around the LiteRtModel C struct.
 /// Usually represents a model loaded from a file.
-pub struct Model {
+///
+/// LiteRT doesn't copy the flatbuffer of a model created from memory, so the model keeps the
+/// memory it was created from alive. `'buf` is the lifetime of a borrowed buffer, models that own
+/// their memory (or were loaded from a file) are `Model<'static>`.
+pub struct Model<'buf> {
     pub(crate) raw_model: LiteRtModel,
+    // Metadata keys added with `add_metadata`, the C API can't enumerate them.
+    added_metadata_keys: Vec<String>,
+    // The memory raw_model reads the flatbuffer from, dropped after the model is destroyed.
+    _buffer: ModelBuffer<'buf>,
+}
+
+/// Alignment of the memory LiteRT reads a model from. Flatbuffers assume that the buffer is
+/// aligned to its largest scalar, LiteRT itself allocates model buffers with this alignment.
+pub const MODEL_BUFFER_ALIGNMENT: usize = 16;
+
+// The memory a model was created from.
+enum ModelBuffer<'buf> {
+    // Loaded from a file, LiteRT owns the data.
+    None,
+    Borrowed(&'buf [u8]),
+    Owned(Vec<u8>),
+    Aligned(AlignedBuffer),
+    Shared(Arc<[u8]>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl ModelBuffer<'_> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            ModelBuffer::None => &[],
+            ModelBuffer::Borrowed(data) => data,
+            ModelBuffer::Owned(data) => data,
+            ModelBuffer::Aligned(data) => data.as_slice(),
+            ModelBuffer::Shared(data) => data,
+            #[cfg(feature = "mmap")]
+            ModelBuffer::Mapped(data) => data,
+        }
+    }
+}
+
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+struct AlignedBlock([u8; MODEL_BUFFER_ALIGNMENT]);
+
+// An owned copy of a model with MODEL_BUFFER_ALIGNMENT alignment.
+struct AlignedBuffer {
+    blocks: Vec<AlignedBlock>,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    fn copy_from(data: &[u8]) -> Self {
+        let mut blocks = vec![
+            AlignedBlock([0; MODEL_BUFFER_ALIGNMENT]);
+            data.len().div_ceil(MODEL_BUFFER_ALIGNMENT)
+        ];
+        for (block, chunk) in blocks.iter_mut().zip(data.chunks(MODEL_BUFFER_ALIGNMENT)) {
+            block.0[..chunk.len()].copy_from_slice(chunk);
+        }
+        AlignedBuffer {
+            blocks,
+            len: data.len(),
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        // SAFETY: blocks is a contiguous allocation of at least len initialized bytes.
+        unsafe { std::slice::from_raw_parts(self.blocks.as_ptr() as *const u8, self.len) }
+    }
+}
+
+fn is_model_buffer_aligned(data: &[u8]) -> bool {
+    (data.as_ptr() as usize).is_multiple_of(MODEL_BUFFER_ALIGNMENT)
+}
+
+// Metadata keys written by the TFLite converter and tooling, used to discover the metadata of
+// loaded models.
+const WELL_KNOWN_METADATA_KEYS: &[&str] = &[
+    "min_runtime_version",
+    "TFLITE_METADATA",
+    "CONVERSION_METADATA",
+    "model_control_dependencies",
+];
+
+/// Options for model serialization.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerializationOptions {
+    /// Alignment of the bytecode assets appended to the model, relative to the first byte of the
+    /// flatbuffer.
+    pub bytecode_alignment: usize,
+}
+
+impl SerializationOptions {
+    fn to_c_struct(self) -> LiteRtModelSerializationOptions {
+        LiteRtModelSerializationOptions {
+            bytecode_alignment: self.bytecode_alignment,
+        }
+    }
+}
+
+extern "C" {
+    // Releases a buffer returned by LiteRtSerializeModel with `delete[]`, matching the
+    // `new uint8_t[]` it's allocated with. Defined in shim/serialized_buffer.cc.
+    fn LiteRtRustDeleteSerializedBuffer(buffer: *mut u8);
 }
 
 /// `Subgraph` is a wrapper around the LiteRtSubgraph C struct.
 /// It represents a subgraph of a model.
 pub struct Subgraph<'a> {
-    raw_subgraph: LiteRtSubgraph,
+    pub(crate) raw_subgraph: LiteRtSubgraph,
     _phantom: PhantomData<&'a LiteRtSubgraph>,
 }
 
@@ -25,6 +134,7 @@ pub struct Subgraph<'a> {
 /// It represents a signature of a model.
 pub struct Signature<'a> {
     raw_signature: LiteRtSignature,
+    index: LiteRtParamIndex,
     _phantom: PhantomData<&'a LiteRtSignature>,
 }
 
@@ -41,7 +151,12 @@ pub struct InputOutputNamesIterator<'a> {
     kind: InputOutputNamesIteratorKind,
 }
 
-impl Signature<'_> {
+impl<'a> Signature<'a> {
+    /// Returns the index of the signature in the model.
+    pub fn index(&self) -> LiteRtParamIndex {
+        self.index
+    }
+
     /// Returns the key of the signature.
     pub fn key(&self) -> Result<&str, Error> {
         let mut key: *const c_char = std::ptr::null_mut();
@@ -113,6 +228,85 @@ impl Signature<'_> {
             kind: InputOutputNamesIteratorKind::Output,
         })
     }
+
+    /// Returns the input tensor with the given signature input name.
+    pub fn input_tensor(&self, name: &str) -> Result<Tensor<'a>, Error> {
+        let name_c_string = signature_tensor_name_to_c_string(name)?;
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_signature is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetSignatureInputTensor(
+                    self.raw_signature,
+                    name_c_string.as_ptr(),
+                    &mut raw_tensor_ptr,
+                )
+            },
+            ErrorCause::GetSignatureInputTensor
+        );
+        Ok(Tensor::new(raw_tensor_ptr))
+    }
+
+    /// Returns the input tensor at the given index of the signature.
+    pub fn input_tensor_by_index(&self, index: LiteRtParamIndex) -> Result<Tensor<'a>, Error> {
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_signature is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetSignatureInputTensorByIndex(self.raw_signature, index, &mut raw_tensor_ptr)
+            },
+            ErrorCause::GetSignatureInputTensor
+        );
+        Ok(Tensor::new(raw_tensor_ptr))
+    }
+
+    /// Returns the output tensor with the given signature output name.
+    pub fn output_tensor(&self, name: &str) -> Result<Tensor<'a>, Error> {
+        let name_c_string = signature_tensor_name_to_c_string(name)?;
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_signature is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetSignatureOutputTensor(
+                    self.raw_signature,
+                    name_c_string.as_ptr(),
+                    &mut raw_tensor_ptr,
+                )
+            },
+            ErrorCause::GetSignatureOutputTensor
+        );
+        Ok(Tensor::new(raw_tensor_ptr))
+    }
+
+    /// Returns the output tensor at the given index of the signature.
+    pub fn output_tensor_by_index(&self, index: LiteRtParamIndex) -> Result<Tensor<'a>, Error> {
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_signature is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetSignatureOutputTensorByIndex(
+                    self.raw_signature,
+                    index,
+                    &mut raw_tensor_ptr,
+                )
+            },
+            ErrorCause::GetSignatureOutputTensor
+        );
+        Ok(Tensor::new(raw_tensor_ptr))
+    }
+}
+
+fn signature_tensor_name_to_c_string(name: &str) -> Result<CString, Error> {
+    CString::new(name).map_err(|_| {
+        Error::new(
+            ErrorCause::InvalidSignatureTensorName,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        )
+    })
 }
 
 impl<'a> Iterator for InputOutputNamesIterator<'a> {
@@ -136,7 +330,11 @@ impl<'a> Iterator for InputOutputNamesIterator<'a> {
                 ),
             };
             if status != LiteRtStatus_kLiteRtStatusOk {
-                return Some(Err(Error::new(ErrorCause::GetSignatureInputName, status)));
+                let cause = match self.kind {
+                    InputOutputNamesIteratorKind::Input => ErrorCause::GetSignatureInputName,
+                    InputOutputNamesIteratorKind::Output => ErrorCause::GetSignatureOutputName,
+                };
+                return Some(Err(Error::new(cause, status)));
             }
         }
         self.index += 1;
@@ -154,7 +352,7 @@ impl<'a> Iterator for InputOutputNamesIterator<'a> {
 
 /// An iterator over the signatures of a model.
 pub struct SignatureIterator<'a> {
-    model: &'a Model,
+    model: &'a Model<'a>,
     index: LiteRtParamIndex,
     total_num_signatures: LiteRtParamIndex,
 }
@@ -165,13 +363,14 @@ impl<'a> Iterator for SignatureIterator<'a> {
         if self.index >= self.total_num_signatures {
             return None;
         }
+        let index = self.index;
         let mut raw_signature_ptr: LiteRtSignature = std::ptr::null_mut();
         // SAFETY: self.model.raw_model is always valid as it's initialized by a wrapper function.
-        // self.index is always valid, it is explicitly limited to the valid range.
+        // index is always valid, it is explicitly limited to the valid range.
         // We assume that the output is valid if the return status is OK or don't use the output pointers.
         unsafe {
             let status =
-                LiteRtGetModelSignature(self.model.raw_model, self.index, &mut raw_signature_ptr);
+                LiteRtGetModelSignature(self.model.raw_model, index, &mut raw_signature_ptr);
             self.index += 1;
             if status != LiteRtStatus_kLiteRtStatusOk {
                 return Some(Err(Error::new(ErrorCause::GetSignature, status)));
@@ -179,6 +378,7 @@ impl<'a> Iterator for SignatureIterator<'a> {
         }
         Some(Ok(Signature {
             raw_signature: raw_signature_ptr,
+            index,
             _phantom: PhantomData {},
         }))
     }
@@ -187,14 +387,225 @@ impl<'a> Iterator for SignatureIterator<'a> {
     }
 }
 
+/// An iterator over the subgraphs of a model.
+pub struct SubgraphIterator<'a> {
+    model: &'a Model<'a>,
+    index: LiteRtParamIndex,
+    total_num_subgraphs: LiteRtParamIndex,
+}
+
+impl<'a> Iterator for SubgraphIterator<'a> {
+    type Item = Result<Subgraph<'a>, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.total_num_subgraphs {
+            return None;
+        }
+        let subgraph = self.model.subgraph(self.index);
+        self.index += 1;
+        Some(subgraph)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total_num_subgraphs - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// `Op` is a wrapper around the LiteRtOp C struct.
+/// It represents an operation (a node) in a subgraph.
+#[derive(Clone, Copy)]
+pub struct Op<'a> {
+    pub(crate) raw_op: LiteRtOp,
+    _phantom: PhantomData<&'a LiteRtOp>,
+}
+
+impl<'a> Op<'a> {
+    /// Returns the code of the operation.
+    pub fn code(&self) -> Result<OpCode, Error> {
+        let mut raw_op_code: LiteRtOpCode = LiteRtOpCode_kLiteRtOpCodeTflAdd;
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetOpCode(self.raw_op, &mut raw_op_code) },
+            ErrorCause::GetOpCode
+        );
+        OpCode::from_c_enum(raw_op_code)
+    }
+
+    /// Returns the custom code of a `TflCustom` operation, `None` for other operations.
+    ///
+    /// The C API doesn't expose the custom options of the operation.
+    pub fn custom_code(&self) -> Result<Option<&'a str>, Error> {
+        if self.code()? != OpCode::TflCustom {
+            return Ok(None);
+        }
+        let mut custom_code: *const c_char = std::ptr::null();
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetCustomCode(self.raw_op, &mut custom_code) },
+            ErrorCause::GetCustomCode
+        );
+        // SAFETY: We assume that if C API returns OK then the output is valid, the string is owned
+        // by the model.
+        unsafe { c_str_to_str(custom_code) }.map(Some)
+    }
+
+    /// Returns the number of inputs of the operation.
+    pub fn num_inputs(&self) -> Result<LiteRtParamIndex, Error> {
+        let mut num_inputs: LiteRtParamIndex = 0;
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            unsafe { LiteRtGetNumOpInputs(self.raw_op, &mut num_inputs) },
+            ErrorCause::GetNumOpInputs
+        );
+        Ok(num_inputs)
+    }
+
+    /// Returns the number of outputs of the operation.
+    pub fn num_outputs(&self) -> Result<LiteRtParamIndex, Error> {
+        let mut num_outputs: LiteRtParamIndex = 0;
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            unsafe { LiteRtGetNumOpOutputs(self.raw_op, &mut num_outputs) },
+            ErrorCause::GetNumOpOutputs
+        );
+        Ok(num_outputs)
+    }
+
+    /// Returns the input tensor at the given index.
+    pub fn input(&self, input_index: LiteRtParamIndex) -> Result<Tensor<'a>, Error> {
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetOpInput(self.raw_op, input_index, &mut raw_tensor_ptr) },
+            ErrorCause::GetOpInput
+        );
+        Ok(Tensor {
+            raw_tensor: raw_tensor_ptr,
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Returns the output tensor at the given index.
+    pub fn output(&self, output_index: LiteRtParamIndex) -> Result<Tensor<'a>, Error> {
+        let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_op is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetOpOutput(self.raw_op, output_index, &mut raw_tensor_ptr) },
+            ErrorCause::GetOpOutput
+        );
+        Ok(Tensor {
+            raw_tensor: raw_tensor_ptr,
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Returns an iterator over the input tensors of the operation.
+    pub fn inputs(&self) -> Result<OpTensorIterator<'a>, Error> {
+        Ok(OpTensorIterator {
+            op: *self,
+            index: 0,
+            total_num_tensors: self.num_inputs()?,
+            kind: OpTensorIteratorKind::Input,
+        })
+    }
+
+    /// Returns an iterator over the output tensors of the operation.
+    pub fn outputs(&self) -> Result<OpTensorIterator<'a>, Error> {
+        Ok(OpTensorIterator {
+            op: *self,
+            index: 0,
+            total_num_tensors: self.num_outputs()?,
+            kind: OpTensorIteratorKind::Output,
+        })
+    }
+}
+
+enum OpTensorIteratorKind {
+    Input,
+    Output,
+}
+
+/// An iterator over the input or output tensors of an operation.
+pub struct OpTensorIterator<'a> {
+    op: Op<'a>,
+    index: LiteRtParamIndex,
+    total_num_tensors: LiteRtParamIndex,
+    kind: OpTensorIteratorKind,
+}
+
+impl<'a> Iterator for OpTensorIterator<'a> {
+    type Item = Result<Tensor<'a>, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.total_num_tensors {
+            return None;
+        }
+        let tensor = match self.kind {
+            OpTensorIteratorKind::Input => self.op.input(self.index),
+            OpTensorIteratorKind::Output => self.op.output(self.index),
+        };
+        self.index += 1;
+        Some(tensor)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total_num_tensors - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// An iterator over the operations of a subgraph.
+pub struct OpIterator<'a> {
+    raw_subgraph: LiteRtSubgraph,
+    index: LiteRtParamIndex,
+    total_num_ops: LiteRtParamIndex,
+    _phantom: PhantomData<&'a LiteRtSubgraph>,
+}
+
+impl<'a> Iterator for OpIterator<'a> {
+    type Item = Result<Op<'a>, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.total_num_ops {
+            return None;
+        }
+        let mut raw_op_ptr: LiteRtOp = std::ptr::null_mut();
+        // SAFETY: self.raw_subgraph is always valid as it's initialized by a wrapper function.
+        // self.index is always valid, it is explicitly limited to the valid range.
+        // We assume that the output is valid if the return status is OK or don't use the output pointers.
+        unsafe {
+            let status = LiteRtGetSubgraphOp(self.raw_subgraph, self.index, &mut raw_op_ptr);
+            self.index += 1;
+            if status != LiteRtStatus_kLiteRtStatusOk {
+                return Some(Err(Error::new(ErrorCause::GetSubgraphOp, status)));
+            }
+        }
+        Some(Ok(Op {
+            raw_op: raw_op_ptr,
+            _phantom: PhantomData {},
+        }))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total_num_ops - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
 /// `Tensor` is a wrapper around the LiteRtTensor C struct.
 /// It represents a tensor in a model.
 pub struct Tensor<'a> {
-    raw_tensor: LiteRtTensor,
+    pub(crate) raw_tensor: LiteRtTensor,
     _phantom: PhantomData<&'a LiteRtTensor>,
 }
 
 impl<'a> Tensor<'a> {
+    pub(crate) fn new(raw_tensor: LiteRtTensor) -> Self {
+        Tensor {
+            raw_tensor,
+            _phantom: PhantomData {},
+        }
+    }
+
     fn type_id(&self) -> Result<LiteRtTensorTypeId, Error> {
         let mut raw_tensor_type = LiteRtTensorTypeId_kLiteRtRankedTensorType;
         call_check_status!(
@@ -230,6 +641,25 @@ impl<'a> Tensor<'a> {
         Ok(raw_tensor_type)
     }
 
+    /// Returns the element type and shape of a ranked tensor.
+    ///
+    /// Unranked tensors have no shape, use [`Tensor::element_type`] for them.
+    pub fn tensor_type(&self) -> Result<TensorType, Error> {
+        match self.type_id()? {
+            LiteRtTensorTypeId_kLiteRtRankedTensorType => {
+                TensorType::from_c_struct(&self.ranked_tensor_type()?)
+            }
+            LiteRtTensorTypeId_kLiteRtUnrankedTensorType => Err(Error::new(
+                ErrorCause::UnrankedTensor,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+            _ => Err(Error::new(
+                ErrorCause::InvalidTensorTypeId,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+
     /// Returns the element type of the tensor.
     pub fn element_type(&self) -> Result<ElementType, Error> {
         match self.type_id()? {
@@ -263,6 +693,309 @@ impl<'a> Tensor<'a> {
         // SAFETY: We assume that if C API returns OK then the output is valid.
         unsafe { c_str_to_str(name) }
     }
+
+    /// Returns the operation that produces the tensor.
+    ///
+    /// Returns `None` for subgraph inputs and constant tensors.
+    pub fn defining_op(&self) -> Result<Option<DefiningOp<'a>>, Error> {
+        let mut has_defining_op = false;
+        let mut defining_op = LiteRtTensorDefiningOp::default();
+        call_check_status!(
+            // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetTensorDefiningOp(self.raw_tensor, &mut has_defining_op, &mut defining_op)
+            },
+            ErrorCause::GetTensorDefiningOp
+        );
+        if !has_defining_op {
+            return Ok(None);
+        }
+        Ok(Some(DefiningOp {
+            op: Op {
+                raw_op: defining_op.op,
+                _phantom: PhantomData {},
+            },
+            output_index: defining_op.op_output_index,
+        }))
+    }
+
+    /// Returns the number of operations that use the tensor as an input.
+    pub fn num_uses(&self) -> Result<LiteRtParamIndex, Error> {
+        let mut num_uses: LiteRtParamIndex = 0;
+        call_check_status!(
+            // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+            unsafe { LiteRtGetNumTensorUses(self.raw_tensor, &mut num_uses) },
+            ErrorCause::GetNumTensorUses
+        );
+        Ok(num_uses)
+    }
+
+    /// Returns an iterator over the operations that use the tensor as an input.
+    pub fn uses(&self) -> Result<TensorUseIterator<'a>, Error> {
+        Ok(TensorUseIterator {
+            raw_tensor: self.raw_tensor,
+            index: 0,
+            total_num_uses: self.num_uses()?,
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Returns the quantization parameters of the tensor.
+    pub fn quantization(&self) -> Result<Quantization, Error> {
+        let mut type_id = LiteRtQuantizationTypeId_kLiteRtQuantizationNone;
+        call_check_status!(
+            // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetQuantizationTypeId(self.raw_tensor, &mut type_id) },
+            ErrorCause::GetQuantizationTypeId
+        );
+        match type_id {
+            LiteRtQuantizationTypeId_kLiteRtQuantizationNone => Ok(Quantization::None),
+            LiteRtQuantizationTypeId_kLiteRtQuantizationPerTensor => {
+                let mut per_tensor = LiteRtQuantizationPerTensor::default();
+                call_check_status!(
+                    // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+                    // We assume that the output is valid if the return status is OK or don't use the output pointer.
+                    unsafe { LiteRtGetPerTensorQuantization(self.raw_tensor, &mut per_tensor) },
+                    ErrorCause::GetPerTensorQuantization
+                );
+                Ok(Quantization::PerTensor {
+                    scale: per_tensor.scale,
+                    zero_point: per_tensor.zero_point,
+                })
+            }
+            LiteRtQuantizationTypeId_kLiteRtQuantizationPerChannel => {
+                let mut per_channel = LiteRtQuantizationPerChannel::default();
+                call_check_status!(
+                    // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+                    // We assume that the output is valid if the return status is OK or don't use the output pointer.
+                    unsafe { LiteRtGetPerChannelQuantization(self.raw_tensor, &mut per_channel) },
+                    ErrorCause::GetPerChannelQuantization
+                );
+                let num_channels = per_channel.num_channels as usize;
+                if num_channels == 0
+                    || per_channel.scales.is_null()
+                    || per_channel.zero_points.is_null()
+                {
+                    return Err(Error::new(
+                        ErrorCause::GetPerChannelQuantization,
+                        LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+                    ));
+                }
+                // SAFETY: The C API returns arrays of num_channels elements owned by the model.
+                let (scales, zero_points) = unsafe {
+                    (
+                        std::slice::from_raw_parts(per_channel.scales, num_channels).to_vec(),
+                        std::slice::from_raw_parts(per_channel.zero_points, num_channels).to_vec(),
+                    )
+                };
+                Ok(Quantization::PerChannel {
+                    scales,
+                    zero_points,
+                    quantized_dimension: per_channel.quantized_dimension,
+                })
+            }
+            _ => Err(Error::new(
+                ErrorCause::UnsupportedQuantizationType,
+                LiteRtStatus_kLiteRtStatusErrorUnsupported,
+            )),
+        }
+    }
+
+    /// Returns the constant data of the tensor.
+    ///
+    /// Tensors that are not constant have empty weights.
+    pub fn weights(&self) -> Result<Weights<'a>, Error> {
+        let mut raw_weights: LiteRtWeights = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetTensorWeights(self.raw_tensor, &mut raw_weights) },
+            ErrorCause::GetTensorWeights
+        );
+        let mut addr: *const c_void = std::ptr::null();
+        let mut size: usize = 0;
+        call_check_status!(
+            // SAFETY: raw_weights is valid as it's just returned by the C API.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe { LiteRtGetWeightsBytes(raw_weights, &mut addr, &mut size) },
+            ErrorCause::GetWeightsBytes
+        );
+        let mut buffer_id: i32 = 0;
+        call_check_status!(
+            // SAFETY: raw_weights is valid as it's just returned by the C API.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetWeightsBufferId(raw_weights, &mut buffer_id) },
+            ErrorCause::GetWeightsBufferId
+        );
+        let data = if addr.is_null() || size == 0 {
+            &[]
+        } else {
+            // SAFETY: The weights are owned by the model and stay valid during its lifetime.
+            unsafe { std::slice::from_raw_parts(addr as *const u8, size) }
+        };
+        Ok(Weights {
+            data,
+            buffer_id,
+            element_type: self.element_type()?,
+        })
+    }
+}
+
+/// The operation that produces a tensor.
+#[derive(Clone, Copy)]
+pub struct DefiningOp<'a> {
+    /// The producing operation.
+    pub op: Op<'a>,
+    /// The index of the tensor among the outputs of the operation.
+    pub output_index: LiteRtParamIndex,
+}
+
+/// A use of a tensor as an operand of an operation.
+#[derive(Clone, Copy)]
+pub struct TensorUse<'a> {
+    /// The consuming operation.
+    pub op: Op<'a>,
+    /// The index of the tensor among the inputs of the operation.
+    pub operand_index: LiteRtParamIndex,
+}
+
+/// An iterator over the uses of a tensor.
+pub struct TensorUseIterator<'a> {
+    raw_tensor: LiteRtTensor,
+    index: LiteRtParamIndex,
+    total_num_uses: LiteRtParamIndex,
+    _phantom: PhantomData<&'a LiteRtTensor>,
+}
+
+impl<'a> Iterator for TensorUseIterator<'a> {
+    type Item = Result<TensorUse<'a>, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.total_num_uses {
+            return None;
+        }
+        let mut raw_op_ptr: LiteRtOp = std::ptr::null_mut();
+        let mut operand_index: LiteRtParamIndex = 0;
+        // SAFETY: self.raw_tensor is always valid as it's initialized by a wrapper function.
+        // self.index is always valid, it is explicitly limited to the valid range.
+        // We assume that the output is valid if the return status is OK or don't use the output pointers.
+        unsafe {
+            let status = LiteRtGetTensorUse(
+                self.raw_tensor,
+                self.index,
+                &mut raw_op_ptr,
+                &mut operand_index,
+            );
+            self.index += 1;
+            if status != LiteRtStatus_kLiteRtStatusOk {
+                return Some(Err(Error::new(ErrorCause::GetTensorUse, status)));
+            }
+        }
+        Some(Ok(TensorUse {
+            op: Op {
+                raw_op: raw_op_ptr,
+                _phantom: PhantomData {},
+            },
+            operand_index,
+        }))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total_num_uses - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Quantization parameters of a tensor.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Quantization {
+    /// The tensor is not quantized.
+    None,
+    /// One scale and zero point for the whole tensor.
+    PerTensor { scale: f32, zero_point: i64 },
+    /// One scale and zero point per slice along `quantized_dimension`.
+    PerChannel {
+        scales: Vec<f32>,
+        zero_points: Vec<i64>,
+        quantized_dimension: i32,
+    },
+}
+
+/// Constant data of a tensor, borrowed from the model.
+#[derive(Clone, Copy, Debug)]
+pub struct Weights<'a> {
+    data: &'a [u8],
+    buffer_id: i32,
+    element_type: ElementType,
+}
+
+impl<'a> Weights<'a> {
+    /// Returns the raw bytes of the weights.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the id of the buffer that holds the weights, ids start from 1.
+    pub fn buffer_id(&self) -> i32 {
+        self.buffer_id
+    }
+
+    /// Returns the element type of the tensor the weights belong to.
+    pub fn element_type(&self) -> ElementType {
+        self.element_type
+    }
+
+    /// Returns true if the tensor has no constant data.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the weights as f32 values, the tensor must be of type Float32.
+    pub fn as_f32(&self) -> Result<&'a [f32], Error> {
+        self.typed_view(ElementType::Float32)
+    }
+
+    /// Returns the weights as i8 values, the tensor must be of type Int8.
+    pub fn as_i8(&self) -> Result<&'a [i8], Error> {
+        self.typed_view(ElementType::Int8)
+    }
+
+    /// Returns the weights as f16 values, the tensor must be of type Float16.
+    #[cfg(feature = "half")]
+    pub fn as_f16(&self) -> Result<&'a [half::f16], Error> {
+        self.typed_view(ElementType::Float16)
+    }
+
+    // Reinterprets the bytes as a slice of T without copying. T must be a plain scalar type for
+    // which any bit pattern is valid.
+    fn typed_view<T: Copy>(&self, expected_type: ElementType) -> Result<&'a [T], Error> {
+        if self.element_type != expected_type {
+            return Err(Error::new(
+                ErrorCause::WeightsElementTypeMismatch,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        if self.data.is_empty() {
+            return Ok(&[]);
+        }
+        if self.data.as_ptr().align_offset(std::mem::align_of::<T>()) != 0
+            || !self.data.len().is_multiple_of(std::mem::size_of::<T>())
+        {
+            return Err(Error::new(
+                ErrorCause::MisalignedWeights,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        // SAFETY: The pointer is aligned for T, the length is a multiple of the size of T and
+        // T is a scalar type for which any bit pattern is valid.
+        Ok(unsafe {
+            std::slice::from_raw_parts(
+                self.data.as_ptr() as *const T,
+                self.data.len() / std::mem::size_of::<T>(),
+            )
+        })
+    }
 }
 
 impl<'a> Subgraph<'a> {
@@ -288,7 +1021,7 @@ impl<'a> Subgraph<'a> {
         Ok(num_outputs)
     }
 
-    fn input_tensor(&self, tensor_index: LiteRtParamIndex) -> Result<Tensor<'_>, Error> {
+    pub(crate) fn input_tensor(&self, tensor_index: LiteRtParamIndex) -> Result<Tensor<'_>, Error> {
         let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
         call_check_status!(
             // SAFETY: self.raw_subgraph is always valid as it's initialized by a wrapper function.
@@ -317,7 +1050,10 @@ impl<'a> Subgraph<'a> {
         ));
     }
 
-    fn output_tensor(&self, tensor_index: LiteRtParamIndex) -> Result<Tensor<'_>, Error> {
+    pub(crate) fn output_tensor(
+        &self,
+        tensor_index: LiteRtParamIndex,
+    ) -> Result<Tensor<'_>, Error> {
         let mut raw_tensor_ptr: LiteRtTensor = std::ptr::null_mut();
         call_check_status!(
             // SAFETY: self.raw_subgraph is always valid as it's initialized by a wrapper function.
@@ -333,10 +1069,61 @@ impl<'a> Subgraph<'a> {
         })
     }
 
+    /// Returns the number of operations in the subgraph.
+    pub fn num_ops(&self) -> Result<LiteRtParamIndex, Error> {
+        let mut num_ops: LiteRtParamIndex = 0;
+        call_check_status!(
+            // SAFETY: self.raw_subgraph is always valid as it's initialized by a wrapper function.
+            unsafe { LiteRtGetNumSubgraphOps(self.raw_subgraph, &mut num_ops) },
+            ErrorCause::GetNumSubgraphOps
+        );
+        Ok(num_ops)
+    }
+
+    /// Returns an iterator over the operations of the subgraph in their stored order.
+    pub fn ops(&self) -> Result<OpIterator<'a>, Error> {
+        Ok(OpIterator {
+            raw_subgraph: self.raw_subgraph,
+            index: 0,
+            total_num_ops: self.num_ops()?,
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Returns the operations of the subgraph sorted so that every operation comes after the
+    /// operations producing its inputs.
+    ///
+    /// Operations without dependencies between them keep their stored order.
+    pub fn topological_order(&self) -> Result<Vec<Op<'a>>, Error> {
+        let ops = self.ops()?.collect::<Result<Vec<_>, _>>()?;
+        let op_indices: HashMap<LiteRtOp, usize> = ops
+            .iter()
+            .enumerate()
+            .map(|(i, op)| (op.raw_op, i))
+            .collect();
+        let mut consumers = vec![Vec::new(); ops.len()];
+        for (i, op) in ops.iter().enumerate() {
+            for input in op.inputs()? {
+                if let Some(defining_op) = input?.defining_op()? {
+                    if let Some(&producer) = op_indices.get(&defining_op.op.raw_op) {
+                        consumers[producer].push(i);
+                    }
+                }
+            }
+        }
+        let Some(order) = topological_sort(&consumers) else {
+            return Err(Error::new(
+                ErrorCause::SubgraphHasCycle,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        };
+        Ok(order.into_iter().map(|i| ops[i]).collect())
+    }
+
     /// Returns the output tensor with the given name.
     pub fn output_tensor_by_name(&self, tensor_name: &str) -> Result<Tensor<'_>, Error> {
-        let num_inputs = self.num_inputs()?;
-        for i in 0..num_inputs {
+        let num_outputs = self.num_outputs()?;
+        for i in 0..num_outputs {
             let tensor = self.output_tensor(i)?;
             if tensor.name()? == tensor_name {
                 return Ok(tensor);
@@ -349,7 +1136,7 @@ impl<'a> Subgraph<'a> {
     }
 }
 
-impl Model {
+impl Model<'static> {
     /// Creates a model from a file path.
     pub fn create_model_from_file(path: &str) -> Result<Self, Error> {
         let path_c_string =
@@ -363,18 +1150,70 @@ impl Model {
         );
         Ok(Model {
             raw_model: raw_model_ptr,
+            added_metadata_keys: Vec::new(),
+            _buffer: ModelBuffer::None,
         })
     }
 
-    /// Creates a model from a memory buffer.
-    pub fn create_model_from_buffer(buffer: &mut [u8]) -> Result<Self, Error> {
+    /// Creates a model that owns `buffer`.
+    /// The buffer is copied if it isn't aligned to [`MODEL_BUFFER_ALIGNMENT`].
+    pub fn create_model_from_vec(buffer: Vec<u8>) -> Result<Self, Error> {
+        if is_model_buffer_aligned(&buffer) {
+            Model::from_model_buffer(ModelBuffer::Owned(buffer))
+        } else {
+            Model::from_model_buffer(ModelBuffer::Aligned(AlignedBuffer::copy_from(&buffer)))
+        }
+    }
+
+    /// Creates a model that shares ownership of `buffer`.
+    /// The buffer is copied if it isn't aligned to [`MODEL_BUFFER_ALIGNMENT`].
+    pub fn create_model_from_shared_buffer(buffer: Arc<[u8]>) -> Result<Self, Error> {
+        if is_model_buffer_aligned(&buffer) {
+            Model::from_model_buffer(ModelBuffer::Shared(buffer))
+        } else {
+            Model::from_model_buffer(ModelBuffer::Aligned(AlignedBuffer::copy_from(&buffer)))
+        }
+    }
+
+    /// Creates a model from a memory-mapped file, the model keeps the mapping alive.
+    ///
+    /// The file must not be modified while it's mapped, see [`memmap2::Mmap::map`].
+    #[cfg(feature = "mmap")]
+    pub fn create_model_from_mmap(mmap: memmap2::Mmap) -> Result<Self, Error> {
+        // Mappings are page aligned.
+        Model::from_model_buffer(ModelBuffer::Mapped(mmap))
+    }
+}
+
+impl<'buf> Model<'buf> {
+    /// Creates a model from a memory buffer, the model borrows the buffer.
+    /// The buffer is copied if it isn't aligned to [`MODEL_BUFFER_ALIGNMENT`], this is usually the
+    /// case for `include_bytes!`.
+    ///
+    /// ```rust,no_run
+    /// let data = std::fs::read("model.tflite")?;
+    /// let model = litert::Model::create_model_from_buffer(&data)?;
+    /// // `data` can't be dropped while `model` is alive.
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn create_model_from_buffer(buffer: &'buf [u8]) -> Result<Self, Error> {
+        if is_model_buffer_aligned(buffer) {
+            Model::from_model_buffer(ModelBuffer::Borrowed(buffer))
+        } else {
+            Model::from_model_buffer(ModelBuffer::Aligned(AlignedBuffer::copy_from(buffer)))
+        }
+    }
+
+    fn from_model_buffer(buffer: ModelBuffer<'buf>) -> Result<Self, Error> {
+        let data = buffer.as_slice();
         let mut raw_model_ptr: LiteRtModel = std::ptr::null_mut();
         call_check_status!(
-            // SAFETY: buffer is a valid pointer to the memory buffer provided by safe Rust code.
+            // SAFETY: data is a valid memory buffer, it's stored in the model with the handle and
+            // moving ModelBuffer doesn't move the memory it points to.
             unsafe {
                 LiteRtCreateModelFromBuffer(
-                    buffer.as_ptr() as *const c_void,
-                    buffer.len(),
+                    data.as_ptr() as *const c_void,
+                    data.len(),
                     &mut raw_model_ptr,
                 )
             },
@@ -382,6 +1221,8 @@ impl Model {
         );
         Ok(Model {
             raw_model: raw_model_ptr,
+            added_metadata_keys: Vec::new(),
+            _buffer: buffer,
         })
     }
 
@@ -396,6 +1237,69 @@ impl Model {
         Ok(num_subgraphs)
     }
 
+    /// Returns the subgraph at the given index.
+    pub fn subgraph(&self, index: LiteRtParamIndex) -> Result<Subgraph<'_>, Error> {
+        let mut raw_subgraph_ptr: LiteRtSubgraph = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe { LiteRtGetModelSubgraph(self.raw_model, index, &mut raw_subgraph_ptr) },
+            ErrorCause::GetModelSubgraph
+        );
+        Ok(Subgraph {
+            raw_subgraph: raw_subgraph_ptr,
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Returns an iterator over the subgraphs of the model.
+    ///
+    /// The body, condition and branch subgraph indices of WHILE, IF and CALL_ONCE operations are
+    /// not available: the C API has no option getters for them. Those subgraphs can only be
+    /// reached by enumerating all subgraphs.
+    pub fn subgraphs(&self) -> Result<SubgraphIterator<'_>, Error> {
+        Ok(SubgraphIterator {
+            model: self,
+            index: 0,
+            total_num_subgraphs: self.num_subgraphs()?,
+        })
+    }
+
+    /// Returns the index of the entry subgraph of the model.
+    pub fn main_subgraph_index(&self) -> Result<LiteRtParamIndex, Error> {
+        let mut main_subgraph_index: LiteRtParamIndex = 0;
+        call_check_status!(
+            // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+            unsafe { LiteRtGetMainModelSubgraphIndex(self.raw_model, &mut main_subgraph_index) },
+            ErrorCause::GetMainModelSubgraphIndex
+        );
+        Ok(main_subgraph_index)
+    }
+
+    /// Returns the entry subgraph of the model.
+    pub fn main_subgraph(&self) -> Result<Subgraph<'_>, Error> {
+        self.subgraph(self.main_subgraph_index()?)
+    }
+
+    /// Returns the distinct custom codes of the custom operations of all subgraphs, in the order
+    /// they first appear.
+    ///
+    /// Custom operations need a resolver registered with the runtime, checking them up front gives
+    /// a better error than a failing `CompiledModel::create`.
+    pub fn custom_ops(&self) -> Result<Vec<&str>, Error> {
+        let mut custom_codes = Vec::new();
+        for subgraph in self.subgraphs()? {
+            for op in subgraph?.ops()? {
+                if let Some(custom_code) = op?.custom_code()? {
+                    if !custom_codes.contains(&custom_code) {
+                        custom_codes.push(custom_code);
+                    }
+                }
+            }
+        }
+        Ok(custom_codes)
+    }
+
     /// Returns the number of signatures in the model.
     pub fn num_signatures(&self) -> Result<LiteRtParamIndex, Error> {
         let mut num_signatures: LiteRtParamIndex = 0;
@@ -428,12 +1332,269 @@ impl Model {
         );
         Ok(Signature {
             raw_signature: raw_signature_ptr,
+            index,
             _phantom: PhantomData,
         })
     }
+
+    /// Returns the signature with the given key.
+    pub fn signature_by_key(&self, key: &str) -> Result<Signature<'_>, Error> {
+        for signature in self.signatures()? {
+            let signature = signature?;
+            if signature.key()? == key {
+                return Ok(signature);
+            }
+        }
+        Err(Error::new(
+            ErrorCause::SignatureNotFound,
+            LiteRtStatus_kLiteRtStatusErrorNotFound,
+        ))
+    }
+
+    /// Returns the signature LiteRT creates for models that don't define any signatures.
+    pub fn default_signature(&self) -> Result<Signature<'_>, Error> {
+        let mut key: *const c_char = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: The C API only writes a pointer to a static string to key.
+            unsafe { LiteRtGetDefaultSignatureKey(&mut key) },
+            ErrorCause::GetDefaultSignatureKey
+        );
+        // SAFETY: We assume that if C API returns OK then the output is valid.
+        let key = unsafe { c_str_to_str(key) }?;
+        self.signature_by_key(key)
+    }
+
+    /// Returns the metadata stored under the given key, or `None` if the model has no such
+    /// metadata.
+    pub fn metadata(&self, key: &str) -> Result<Option<&[u8]>, Error> {
+        let key_c_string = CString::new(key).map_err(|_| {
+            Error::new(
+                ErrorCause::InvalidMetadataKey,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )
+        })?;
+        let mut buffer: *const c_void = std::ptr::null();
+        let mut buffer_size: usize = 0;
+        // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+        // key_c_string is a valid C string that outlives the call.
+        // We assume that the output is valid if the return status is OK or don't use the output pointers.
+        let status = unsafe {
+            LiteRtGetModelMetadata(
+                self.raw_model,
+                key_c_string.as_ptr(),
+                &mut buffer,
+                &mut buffer_size,
+            )
+        };
+        if status == LiteRtStatus_kLiteRtStatusErrorNotFound {
+            return Ok(None);
+        }
+        if status != LiteRtStatus_kLiteRtStatusOk {
+            return Err(Error::new(ErrorCause::GetModelMetadata, status));
+        }
+        if buffer.is_null() || buffer_size == 0 {
+            return Ok(Some(&[]));
+        }
+        // SAFETY: The metadata buffer is owned by the model and stays valid while it's borrowed.
+        Ok(Some(unsafe {
+            std::slice::from_raw_parts(buffer as *const u8, buffer_size)
+        }))
+    }
+
+    /// Returns the metadata keys of the model that are known to the wrapper.
+    ///
+    /// This is not a complete listing: the C API can't enumerate the metadata of a model, so the
+    /// result only contains the well-known TFLite keys (`min_runtime_version`, `TFLITE_METADATA`,
+    /// `CONVERSION_METADATA` and `model_control_dependencies`) present in the model and the keys
+    /// added with `add_metadata`. Metadata stored under any other key is never listed, it can
+    /// still be read with `metadata` if its key is known.
+    pub fn known_metadata_keys(&self) -> Result<Vec<String>, Error> {
+        let mut keys = Vec::new();
+        for key in WELL_KNOWN_METADATA_KEYS {
+            if self.metadata(key)?.is_some() {
+                keys.push(key.to_string());
+            }
+        }
+        keys.extend(self.added_metadata_keys.iter().cloned());
+        Ok(keys)
+    }
+
+    /// Adds metadata to the model, the data is copied.
+    ///
+    /// Fails if the model already has metadata with the same key.
+    pub fn add_metadata(&mut self, key: &str, data: &[u8]) -> Result<(), Error> {
+        let key_c_string = CString::new(key).map_err(|_| {
+            Error::new(
+                ErrorCause::InvalidMetadataKey,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )
+        })?;
+        call_check_status!(
+            // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+            // key_c_string and data are valid for the duration of the call, the C API copies them.
+            unsafe {
+                LiteRtAddModelMetadata(
+                    self.raw_model,
+                    key_c_string.as_ptr(),
+                    data.as_ptr() as *const c_void,
+                    data.len(),
+                )
+            },
+            ErrorCause::AddModelMetadata
+        );
+        if !WELL_KNOWN_METADATA_KEYS.contains(&key) {
+            self.added_metadata_keys.push(key.to_string());
+        }
+        Ok(())
+    }
+
+    /// Serializes the model to `.tflite` flatbuffer bytes.
+    ///
+    /// The model is consumed because LiteRT moves the model content into the serialized buffer.
+    /// Serialization is not supported by LiteRT builds without NPU support.
+    pub fn serialize(self, options: &SerializationOptions) -> Result<Vec<u8>, Error> {
+        let mut buffer: *mut u8 = std::ptr::null_mut();
+        let mut size: usize = 0;
+        let mut offset: usize = 0;
+        call_check_status!(
+            // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+            // The model isn't destroyed by the C API, it's destroyed when self is dropped.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtSerializeModel(
+                    self.raw_model,
+                    &mut buffer,
+                    &mut size,
+                    &mut offset,
+                    false,
+                    options.to_c_struct(),
+                )
+            },
+            ErrorCause::SerializeModel
+        );
+        // SAFETY: The C API returns a buffer of `size` bytes owned by the caller.
+        Ok(unsafe { take_serialized_buffer(buffer, size, offset) })
+    }
+
+    /// Serializes the model to `.tflite` flatbuffer bytes, assigning a signature key to every
+    /// subgraph.
+    ///
+    /// `signature_keys` must have one key per subgraph, `None` keeps the default signature.
+    /// The model is consumed, see `serialize`.
+    pub fn serialize_with_signatures(
+        self,
+        signature_keys: &[Option<&str>],
+        options: &SerializationOptions,
+    ) -> Result<Vec<u8>, Error> {
+        let keys = signature_keys
+            .iter()
+            .map(|key| key.map(CString::new).transpose())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                Error::new(
+                    ErrorCause::InvalidSignatureKey,
+                    LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+                )
+            })?;
+        let mut key_ptrs: Vec<*mut c_char> = keys
+            .iter()
+            .map(|key| {
+                key.as_ref()
+                    .map_or(std::ptr::null_mut(), |k| k.as_ptr() as *mut c_char)
+            })
+            .collect();
+        let mut buffer: *mut u8 = std::ptr::null_mut();
+        let mut size: usize = 0;
+        let mut offset: usize = 0;
+        call_check_status!(
+            // SAFETY: self.raw_model is always valid as it's initialized by a wrapper function.
+            // key_ptrs points to valid C strings or nulls that outlive the call, the C API only
+            // reads them.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtSerializeModelWithSignatures(
+                    self.raw_model,
+                    &mut buffer,
+                    &mut size,
+                    &mut offset,
+                    false,
+                    key_ptrs.as_mut_ptr(),
+                    key_ptrs.len() as LiteRtParamIndex,
+                    options.to_c_struct(),
+                )
+            },
+            ErrorCause::SerializeModel
+        );
+        // SAFETY: The C API returns a buffer of `size` bytes owned by the caller.
+        Ok(unsafe { take_serialized_buffer(buffer, size, offset) })
+    }
+
+    /// Serializes the model and writes it to a file.
+    ///
+    /// The model is consumed, see `serialize`.
+    pub fn save<P: AsRef<std::path::Path>>(
+        self,
+        path: P,
+        options: &SerializationOptions,
+    ) -> Result<(), Error> {
+        let bytes = self.serialize(options)?;
+        std::fs::write(path, bytes).map_err(|_| {
+            Error::new(
+                ErrorCause::WriteModelFile,
+                LiteRtStatus_kLiteRtStatusErrorFileIO,
+            )
+        })
+    }
+}
+
+// Copies the valid part of a serialized model, buffer[offset..size], and releases the buffer.
+// SAFETY: The function is unsafe because `buffer` must be a buffer of `size` bytes returned by
+// LiteRtSerializeModel and not used after the call.
+unsafe fn take_serialized_buffer(buffer: *mut u8, size: usize, offset: usize) -> Vec<u8> {
+    if buffer.is_null() {
+        return Vec::new();
+    }
+    // SAFETY: The caller guarantees that buffer points to size valid bytes.
+    let bytes = unsafe { std::slice::from_raw_parts(buffer, size) };
+    let result = bytes[offset.min(size)..].to_vec();
+    // SAFETY: The buffer is owned by the caller of the C API and isn't used anymore.
+    unsafe { LiteRtRustDeleteSerializedBuffer(buffer) };
+    result
+}
+
+// Sorts the nodes of a graph given as adjacency lists so that every node comes after its
+// predecessors, ties are resolved by the node index. Returns None if the graph has a cycle.
+fn topological_sort(successors: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut in_degree = vec![0usize; successors.len()];
+    for node_successors in successors {
+        for &successor in node_successors {
+            in_degree[successor] += 1;
+        }
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> = in_degree
+        .iter()
+        .enumerate()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(node, _)| Reverse(node))
+        .collect();
+    let mut order = Vec::with_capacity(successors.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node);
+        for &successor in &successors[node] {
+            in_degree[successor] -= 1;
+            if in_degree[successor] == 0 {
+                ready.push(Reverse(successor));
+            }
+        }
+    }
+    if order.len() == successors.len() {
+        Some(order)
+    } else {
+        None
+    }
 }
 
-impl Drop for Model {
+impl Drop for Model<'_> {
     fn drop(&mut self) {
         // SAFETY: self.raw_model is always valid, it's guaranteed to be initialized by
         // create* function.
@@ -442,3 +1603,29 @@ impl Drop for Model {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_aligned_buffer_copy() {
+        let data: Vec<u8> = (0..37).collect();
+        let buffer = AlignedBuffer::copy_from(&data[1..]);
+        assert!(is_model_buffer_aligned(buffer.as_slice()));
+        assert_eq!(buffer.as_slice(), &data[1..]);
+        assert!(AlignedBuffer::copy_from(&[]).as_slice().is_empty());
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        assert_eq!(
+            topological_sort(&[vec![], vec![0], vec![1]]),
+            Some(vec![2, 1, 0])
+        );
+        assert_eq!(
+            topological_sort(&[vec![2], vec![2], vec![]]),
+            Some(vec![0, 1, 2])
+        );
+        assert_eq!(topological_sort(&[vec![1], vec![0]]), None);
+    }
+}
@@ -0,0 +1,698 @@
+//! Operation codes of the model's graph nodes.
+//!
+//! `OpCode` mirrors the `LiteRtOpCode` C enum from `litert_op_code.h`.
+#![allow(non_upper_case_globals)]
+
+use crate::bindings::*;
+use crate::error::{Error, ErrorCause};
+
+/// The code of an operation in a model's graph.
+///
+/// `Tfl*` codes correspond to the TFLite builtin operators, `Shlo*` codes correspond to the
+/// StableHLO operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OpCode {
+    TflAdd,
+    TflAveragePool2d,
+    TflConcatenation,
+    TflConv2d,
+    TflDepthwiseConv2d,
+    TflDepthToSpace,
+    TflDequantize,
+    TflEmbeddingLookup,
+    TflFloor,
+    TflFullyConnected,
+    TflHashtableLookup,
+    TflL2Normalization,
+    TflL2Pool2d,
+    TflLocalResponseNormalization,
+    TflLogistic,
+    TflLshProjection,
+    TflLstm,
+    TflMaxPool2d,
+    TflMul,
+    TflRelu,
+    TflReluN1To1,
+    TflRelu6,
+    TflReshape,
+    TflResizeBilinear,
+    TflRnn,
+    TflSoftmax,
+    TflSpaceToDepth,
+    TflSvdf,
+    TflTanh,
+    TflConcatEmbeddings,
+    TflSkipGram,
+    TflCall,
+    TflCustom,
+    TflEmbeddingLookupSparse,
+    TflPad,
+    TflUnidirectionalSequenceRnn,
+    TflGather,
+    TflBatchToSpaceNd,
+    TflSpaceToBatchNd,
+    TflTranspose,
+    TflMean,
+    TflSub,
+    TflDiv,
+    TflSqueeze,
+    TflUnidirectionalSequenceLstm,
+    TflStridedSlice,
+    TflBidirectionalSequenceRnn,
+    TflExp,
+    TflTopkV2,
+    TflSplit,
+    TflLogSoftmax,
+    TflDelegate,
+    TflBidirectionalSequenceLstm,
+    TflCast,
+    TflPrelu,
+    TflMaximum,
+    TflArgMax,
+    TflMinimum,
+    TflLess,
+    TflNeg,
+    TflPadv2,
+    TflGreater,
+    TflGreaterEqual,
+    TflLessEqual,
+    TflSelect,
+    TflSlice,
+    TflSin,
+    TflTransposeConv,
+    TflSparseToDense,
+    TflTile,
+    TflExpandDims,
+    TflEqual,
+    TflNotEqual,
+    TflLog,
+    TflSum,
+    TflSqrt,
+    TflRsqrt,
+    TflShape,
+    TflPow,
+    TflArgMin,
+    TflFakeQuant,
+    TflReduceProd,
+    TflReduceMax,
+    TflPack,
+    TflLogicalOr,
+    TflOneHot,
+    TflLogicalAnd,
+    TflLogicalNot,
+    TflUnpack,
+    TflReduceMin,
+    TflFloorDiv,
+    TflReduceAny,
+    TflSquare,
+    TflZerosLike,
+    TflFill,
+    TflFloorMod,
+    TflRange,
+    TflResizeNearestNeighbor,
+    TflLeakyRelu,
+    TflSquaredDifference,
+    TflMirrorPad,
+    TflAbs,
+    TflSplitV,
+    TflUnique,
+    TflCeil,
+    TflReverseV2,
+    TflAddN,
+    TflGatherNd,
+    TflCos,
+    TflWhere,
+    TflRank,
+    TflElu,
+    TflReverseSequence,
+    TflMatrixDiag,
+    TflQuantize,
+    TflMatrixSetDiag,
+    TflRound,
+    TflHardSwish,
+    TflIf,
+    TflWhile,
+    TflNonMaxSuppressionV4,
+    TflNonMaxSuppressionV5,
+    TflScatterNd,
+    TflSelectV2,
+    TflDensify,
+    TflSegmentSum,
+    TflBatchMatmul,
+    TflPlaceholderForGreaterOpCodeTfls,
+    TflCumsum,
+    TflCallOnce,
+    TflBroadcastTo,
+    TflRfft2d,
+    TflConv3d,
+    TflImag,
+    TflReal,
+    TflComplexAbs,
+    TflHashtable,
+    TflHashtableFind,
+    TflHashtableImport,
+    TflHashtableSize,
+    TflReduceAll,
+    TflConv3dTranspose,
+    TflVarHandle,
+    TflReadVariable,
+    TflAssignVariable,
+    TflBroadcastArgs,
+    TflRandomStandardNormal,
+    TflBucketize,
+    TflRandomUniform,
+    TflMultinomial,
+    TflGelu,
+    TflDynamicUpdateSlice,
+    TflRelu0To1,
+    TflUnsortedSegmentProd,
+    TflUnsortedSegmentMax,
+    TflUnsortedSegmentSum,
+    TflAtan2,
+    TflUnsortedSegmentMin,
+    TflSign,
+    TflBitcast,
+    TflBitwiseXor,
+    TflRightShift,
+    ShloLogistic,
+    ShloAdd,
+    ShloDivide,
+    ShloMultiply,
+    ShloMaximum,
+    ShloReshape,
+    ShloClamp,
+    ShloConcatenate,
+    ShloBroadcastInDim,
+    ShloConvolution,
+    ShloSlice,
+    ShloCustomCall,
+    ShloReduce,
+    ShloAbs,
+    ShloAnd,
+    ShloCosine,
+    ShloExponential,
+    ShloFloor,
+    ShloLog,
+    ShloMinimum,
+    ShloNegate,
+    ShloOr,
+    ShloPower,
+    ShloRemainder,
+    ShloRsqrt,
+    ShloSelect,
+    ShloSubtract,
+    ShloTanh,
+    ShloScatter,
+    ShloCompare,
+    ShloConvert,
+    ShloDynamicSlice,
+    ShloDynamicUpdateSlice,
+    ShloPad,
+    ShloIota,
+    ShloGeneral,
+    ShloWindow,
+    ShloSort,
+    ShloWhile,
+    ShloGather,
+    ShloTranspose,
+    TflDilate,
+    ShloRngBitGenerator,
+    TflReduceWindow,
+    ShloComposite,
+}
+
+impl OpCode {
+    #[allow(dead_code)]
+    pub(crate) fn to_c_enum(self) -> LiteRtOpCode {
+        match self {
+            Self::TflAdd => LiteRtOpCode_kLiteRtOpCodeTflAdd,
+            Self::TflAveragePool2d => LiteRtOpCode_kLiteRtOpCodeTflAveragePool2d,
+            Self::TflConcatenation => LiteRtOpCode_kLiteRtOpCodeTflConcatenation,
+            Self::TflConv2d => LiteRtOpCode_kLiteRtOpCodeTflConv2d,
+            Self::TflDepthwiseConv2d => LiteRtOpCode_kLiteRtOpCodeTflDepthwiseConv2d,
+            Self::TflDepthToSpace => LiteRtOpCode_kLiteRtOpCodeTflDepthToSpace,
+            Self::TflDequantize => LiteRtOpCode_kLiteRtOpCodeTflDequantize,
+            Self::TflEmbeddingLookup => LiteRtOpCode_kLiteRtOpCodeTflEmbeddingLookup,
+            Self::TflFloor => LiteRtOpCode_kLiteRtOpCodeTflFloor,
+            Self::TflFullyConnected => LiteRtOpCode_kLiteRtOpCodeTflFullyConnected,
+            Self::TflHashtableLookup => LiteRtOpCode_kLiteRtOpCodeTflHashtableLookup,
+            Self::TflL2Normalization => LiteRtOpCode_kLiteRtOpCodeTflL2Normalization,
+            Self::TflL2Pool2d => LiteRtOpCode_kLiteRtOpCodeTflL2Pool2d,
+            Self::TflLocalResponseNormalization => {
+                LiteRtOpCode_kLiteRtOpCodeTflLocalResponseNormalization
+            }
+            Self::TflLogistic => LiteRtOpCode_kLiteRtOpCodeTflLogistic,
+            Self::TflLshProjection => LiteRtOpCode_kLiteRtOpCodeTflLshProjection,
+            Self::TflLstm => LiteRtOpCode_kLiteRtOpCodeTflLstm,
+            Self::TflMaxPool2d => LiteRtOpCode_kLiteRtOpCodeTflMaxPool2d,
+            Self::TflMul => LiteRtOpCode_kLiteRtOpCodeTflMul,
+            Self::TflRelu => LiteRtOpCode_kLiteRtOpCodeTflRelu,
+            Self::TflReluN1To1 => LiteRtOpCode_kLiteRtOpCodeTflReluN1To1,
+            Self::TflRelu6 => LiteRtOpCode_kLiteRtOpCodeTflRelu6,
+            Self::TflReshape => LiteRtOpCode_kLiteRtOpCodeTflReshape,
+            Self::TflResizeBilinear => LiteRtOpCode_kLiteRtOpCodeTflResizeBilinear,
+            Self::TflRnn => LiteRtOpCode_kLiteRtOpCodeTflRnn,
+            Self::TflSoftmax => LiteRtOpCode_kLiteRtOpCodeTflSoftmax,
+            Self::TflSpaceToDepth => LiteRtOpCode_kLiteRtOpCodeTflSpaceToDepth,
+            Self::TflSvdf => LiteRtOpCode_kLiteRtOpCodeTflSvdf,
+            Self::TflTanh => LiteRtOpCode_kLiteRtOpCodeTflTanh,
+            Self::TflConcatEmbeddings => LiteRtOpCode_kLiteRtOpCodeTflConcatEmbeddings,
+            Self::TflSkipGram => LiteRtOpCode_kLiteRtOpCodeTflSkipGram,
+            Self::TflCall => LiteRtOpCode_kLiteRtOpCodeTflCall,
+            Self::TflCustom => LiteRtOpCode_kLiteRtOpCodeTflCustom,
+            Self::TflEmbeddingLookupSparse => LiteRtOpCode_kLiteRtOpCodeTflEmbeddingLookupSparse,
+            Self::TflPad => LiteRtOpCode_kLiteRtOpCodeTflPad,
+            Self::TflUnidirectionalSequenceRnn => {
+                LiteRtOpCode_kLiteRtOpCodeTflUnidirectionalSequenceRnn
+            }
+            Self::TflGather => LiteRtOpCode_kLiteRtOpCodeTflGather,
+            Self::TflBatchToSpaceNd => LiteRtOpCode_kLiteRtOpCodeTflBatchToSpaceNd,
+            Self::TflSpaceToBatchNd => LiteRtOpCode_kLiteRtOpCodeTflSpaceToBatchNd,
+            Self::TflTranspose => LiteRtOpCode_kLiteRtOpCodeTflTranspose,
+            Self::TflMean => LiteRtOpCode_kLiteRtOpCodeTflMean,
+            Self::TflSub => LiteRtOpCode_kLiteRtOpCodeTflSub,
+            Self::TflDiv => LiteRtOpCode_kLiteRtOpCodeTflDiv,
+            Self::TflSqueeze => LiteRtOpCode_kLiteRtOpCodeTflSqueeze,
+            Self::TflUnidirectionalSequenceLstm => {
+                LiteRtOpCode_kLiteRtOpCodeTflUnidirectionalSequenceLstm
+            }
+            Self::TflStridedSlice => LiteRtOpCode_kLiteRtOpCodeTflStridedSlice,
+            Self::TflBidirectionalSequenceRnn => {
+                LiteRtOpCode_kLiteRtOpCodeTflBidirectionalSequenceRnn
+            }
+            Self::TflExp => LiteRtOpCode_kLiteRtOpCodeTflExp,
+            Self::TflTopkV2 => LiteRtOpCode_kLiteRtOpCodeTflTopkV2,
+            Self::TflSplit => LiteRtOpCode_kLiteRtOpCodeTflSplit,
+            Self::TflLogSoftmax => LiteRtOpCode_kLiteRtOpCodeTflLogSoftmax,
+            Self::TflDelegate => LiteRtOpCode_kLiteRtOpCodeTflDelegate,
+            Self::TflBidirectionalSequenceLstm => {
+                LiteRtOpCode_kLiteRtOpCodeTflBidirectionalSequenceLstm
+            }
+            Self::TflCast => LiteRtOpCode_kLiteRtOpCodeTflCast,
+            Self::TflPrelu => LiteRtOpCode_kLiteRtOpCodeTflPrelu,
+            Self::TflMaximum => LiteRtOpCode_kLiteRtOpCodeTflMaximum,
+            Self::TflArgMax => LiteRtOpCode_kLiteRtOpCodeTflArgMax,
+            Self::TflMinimum => LiteRtOpCode_kLiteRtOpCodeTflMinimum,
+            Self::TflLess => LiteRtOpCode_kLiteRtOpCodeTflLess,
+            Self::TflNeg => LiteRtOpCode_kLiteRtOpCodeTflNeg,
+            Self::TflPadv2 => LiteRtOpCode_kLiteRtOpCodeTflPadv2,
+            Self::TflGreater => LiteRtOpCode_kLiteRtOpCodeTflGreater,
+            Self::TflGreaterEqual => LiteRtOpCode_kLiteRtOpCodeTflGreaterEqual,
+            Self::TflLessEqual => LiteRtOpCode_kLiteRtOpCodeTflLessEqual,
+            Self::TflSelect => LiteRtOpCode_kLiteRtOpCodeTflSelect,
+            Self::TflSlice => LiteRtOpCode_kLiteRtOpCodeTflSlice,
+            Self::TflSin => LiteRtOpCode_kLiteRtOpCodeTflSin,
+            Self::TflTransposeConv => LiteRtOpCode_kLiteRtOpCodeTflTransposeConv,
+            Self::TflSparseToDense => LiteRtOpCode_kLiteRtOpCodeTflSparseToDense,
+            Self::TflTile => LiteRtOpCode_kLiteRtOpCodeTflTile,
+            Self::TflExpandDims => LiteRtOpCode_kLiteRtOpCodeTflExpandDims,
+            Self::TflEqual => LiteRtOpCode_kLiteRtOpCodeTflEqual,
+            Self::TflNotEqual => LiteRtOpCode_kLiteRtOpCodeTflNotEqual,
+            Self::TflLog => LiteRtOpCode_kLiteRtOpCodeTflLog,
+            Self::TflSum => LiteRtOpCode_kLiteRtOpCodeTflSum,
+            Self::TflSqrt => LiteRtOpCode_kLiteRtOpCodeTflSqrt,
+            Self::TflRsqrt => LiteRtOpCode_kLiteRtOpCodeTflRsqrt,
+            Self::TflShape => LiteRtOpCode_kLiteRtOpCodeTflShape,
+            Self::TflPow => LiteRtOpCode_kLiteRtOpCodeTflPow,
+            Self::TflArgMin => LiteRtOpCode_kLiteRtOpCodeTflArgMin,
+            Self::TflFakeQuant => LiteRtOpCode_kLiteRtOpCodeTflFakeQuant,
+            Self::TflReduceProd => LiteRtOpCode_kLiteRtOpCodeTflReduceProd,
+            Self::TflReduceMax => LiteRtOpCode_kLiteRtOpCodeTflReduceMax,
+            Self::TflPack => LiteRtOpCode_kLiteRtOpCodeTflPack,
+            Self::TflLogicalOr => LiteRtOpCode_kLiteRtOpCodeTflLogicalOr,
+            Self::TflOneHot => LiteRtOpCode_kLiteRtOpCodeTflOneHot,
+            Self::TflLogicalAnd => LiteRtOpCode_kLiteRtOpCodeTflLogicalAnd,
+            Self::TflLogicalNot => LiteRtOpCode_kLiteRtOpCodeTflLogicalNot,
+            Self::TflUnpack => LiteRtOpCode_kLiteRtOpCodeTflUnpack,
+            Self::TflReduceMin => LiteRtOpCode_kLiteRtOpCodeTflReduceMin,
+            Self::TflFloorDiv => LiteRtOpCode_kLiteRtOpCodeTflFloorDiv,
+            Self::TflReduceAny => LiteRtOpCode_kLiteRtOpCodeTflReduceAny,
+            Self::TflSquare => LiteRtOpCode_kLiteRtOpCodeTflSquare,
+            Self::TflZerosLike => LiteRtOpCode_kLiteRtOpCodeTflZerosLike,
+            Self::TflFill => LiteRtOpCode_kLiteRtOpCodeTflFill,
+            Self::TflFloorMod => LiteRtOpCode_kLiteRtOpCodeTflFloorMod,
+            Self::TflRange => LiteRtOpCode_kLiteRtOpCodeTflRange,
+            Self::TflResizeNearestNeighbor => LiteRtOpCode_kLiteRtOpCodeTflResizeNearestNeighbor,
+            Self::TflLeakyRelu => LiteRtOpCode_kLiteRtOpCodeTflLeakyRelu,
+            Self::TflSquaredDifference => LiteRtOpCode_kLiteRtOpCodeTflSquaredDifference,
+            Self::TflMirrorPad => LiteRtOpCode_kLiteRtOpCodeTflMirrorPad,
+            Self::TflAbs => LiteRtOpCode_kLiteRtOpCodeTflAbs,
+            Self::TflSplitV => LiteRtOpCode_kLiteRtOpCodeTflSplitV,
+            Self::TflUnique => LiteRtOpCode_kLiteRtOpCodeTflUnique,
+            Self::TflCeil => LiteRtOpCode_kLiteRtOpCodeTflCeil,
+            Self::TflReverseV2 => LiteRtOpCode_kLiteRtOpCodeTflReverseV2,
+            Self::TflAddN => LiteRtOpCode_kLiteRtOpCodeTflAddN,
+            Self::TflGatherNd => LiteRtOpCode_kLiteRtOpCodeTflGatherNd,
+            Self::TflCos => LiteRtOpCode_kLiteRtOpCodeTflCos,
+            Self::TflWhere => LiteRtOpCode_kLiteRtOpCodeTflWhere,
+            Self::TflRank => LiteRtOpCode_kLiteRtOpCodeTflRank,
+            Self::TflElu => LiteRtOpCode_kLiteRtOpCodeTflElu,
+            Self::TflReverseSequence => LiteRtOpCode_kLiteRtOpCodeTflReverseSequence,
+            Self::TflMatrixDiag => LiteRtOpCode_kLiteRtOpCodeTflMatrixDiag,
+            Self::TflQuantize => LiteRtOpCode_kLiteRtOpCodeTflQuantize,
+            Self::TflMatrixSetDiag => LiteRtOpCode_kLiteRtOpCodeTflMatrixSetDiag,
+            Self::TflRound => LiteRtOpCode_kLiteRtOpCodeTflRound,
+            Self::TflHardSwish => LiteRtOpCode_kLiteRtOpCodeTflHardSwish,
+            Self::TflIf => LiteRtOpCode_kLiteRtOpCodeTflIf,
+            Self::TflWhile => LiteRtOpCode_kLiteRtOpCodeTflWhile,
+            Self::TflNonMaxSuppressionV4 => LiteRtOpCode_kLiteRtOpCodeTflNonMaxSuppressionV4,
+            Self::TflNonMaxSuppressionV5 => LiteRtOpCode_kLiteRtOpCodeTflNonMaxSuppressionV5,
+            Self::TflScatterNd => LiteRtOpCode_kLiteRtOpCodeTflScatterNd,
+            Self::TflSelectV2 => LiteRtOpCode_kLiteRtOpCodeTflSelectV2,
+            Self::TflDensify => LiteRtOpCode_kLiteRtOpCodeTflDensify,
+            Self::TflSegmentSum => LiteRtOpCode_kLiteRtOpCodeTflSegmentSum,
+            Self::TflBatchMatmul => LiteRtOpCode_kLiteRtOpCodeTflBatchMatmul,
+            Self::TflPlaceholderForGreaterOpCodeTfls => {
+                LiteRtOpCode_kLiteRtOpCodeTflPlaceholderForGreaterOpCodeTfls
+            }
+            Self::TflCumsum => LiteRtOpCode_kLiteRtOpCodeTflCumsum,
+            Self::TflCallOnce => LiteRtOpCode_kLiteRtOpCodeTflCallOnce,
+            Self::TflBroadcastTo => LiteRtOpCode_kLiteRtOpCodeTflBroadcastTo,
+            Self::TflRfft2d => LiteRtOpCode_kLiteRtOpCodeTflRfft2d,
+            Self::TflConv3d => LiteRtOpCode_kLiteRtOpCodeTflConv3d,
+            Self::TflImag => LiteRtOpCode_kLiteRtOpCodeTflImag,
+            Self::TflReal => LiteRtOpCode_kLiteRtOpCodeTflReal,
+            Self::TflComplexAbs => LiteRtOpCode_kLiteRtOpCodeTflComplexAbs,
+            Self::TflHashtable => LiteRtOpCode_kLiteRtOpCodeTflHashtable,
+            Self::TflHashtableFind => LiteRtOpCode_kLiteRtOpCodeTflHashtableFind,
+            Self::TflHashtableImport => LiteRtOpCode_kLiteRtOpCodeTflHashtableImport,
+            Self::TflHashtableSize => LiteRtOpCode_kLiteRtOpCodeTflHashtableSize,
+            Self::TflReduceAll => LiteRtOpCode_kLiteRtOpCodeTflReduceAll,
+            Self::TflConv3dTranspose => LiteRtOpCode_kLiteRtOpCodeTflConv3dTranspose,
+            Self::TflVarHandle => LiteRtOpCode_kLiteRtOpCodeTflVarHandle,
+            Self::TflReadVariable => LiteRtOpCode_kLiteRtOpCodeTflReadVariable,
+            Self::TflAssignVariable => LiteRtOpCode_kLiteRtOpCodeTflAssignVariable,
+            Self::TflBroadcastArgs => LiteRtOpCode_kLiteRtOpCodeTflBroadcastArgs,
+            Self::TflRandomStandardNormal => LiteRtOpCode_kLiteRtOpCodeTflRandomStandardNormal,
+            Self::TflBucketize => LiteRtOpCode_kLiteRtOpCodeTflBucketize,
+            Self::TflRandomUniform => LiteRtOpCode_kLiteRtOpCodeTflRandomUniform,
+            Self::TflMultinomial => LiteRtOpCode_kLiteRtOpCodeTflMultinomial,
+            Self::TflGelu => LiteRtOpCode_kLiteRtOpCodeTflGelu,
+            Self::TflDynamicUpdateSlice => LiteRtOpCode_kLiteRtOpCodeTflDynamicUpdateSlice,
+            Self::TflRelu0To1 => LiteRtOpCode_kLiteRtOpCodeTflRelu0To1,
+            Self::TflUnsortedSegmentProd => LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentProd,
+            Self::TflUnsortedSegmentMax => LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentMax,
+            Self::TflUnsortedSegmentSum => LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentSum,
+            Self::TflAtan2 => LiteRtOpCode_kLiteRtOpCodeTflAtan2,
+            Self::TflUnsortedSegmentMin => LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentMin,
+            Self::TflSign => LiteRtOpCode_kLiteRtOpCodeTflSign,
+            Self::TflBitcast => LiteRtOpCode_kLiteRtOpCodeTflBitcast,
+            Self::TflBitwiseXor => LiteRtOpCode_kLiteRtOpCodeTflBitwiseXor,
+            Self::TflRightShift => LiteRtOpCode_kLiteRtOpCodeTflRightShift,
+            Self::ShloLogistic => LiteRtOpCode_kLiteRtOpCodeShloLogistic,
+            Self::ShloAdd => LiteRtOpCode_kLiteRtOpCodeShloAdd,
+            Self::ShloDivide => LiteRtOpCode_kLiteRtOpCodeShloDivide,
+            Self::ShloMultiply => LiteRtOpCode_kLiteRtOpCodeShloMultiply,
+            Self::ShloMaximum => LiteRtOpCode_kLiteRtOpCodeShloMaximum,
+            Self::ShloReshape => LiteRtOpCode_kLiteRtOpCodeShloReshape,
+            Self::ShloClamp => LiteRtOpCode_kLiteRtOpCodeShloClamp,
+            Self::ShloConcatenate => LiteRtOpCode_kLiteRtOpCodeShloConcatenate,
+            Self::ShloBroadcastInDim => LiteRtOpCode_kLiteRtOpCodeShloBroadcastInDim,
+            Self::ShloConvolution => LiteRtOpCode_kLiteRtOpCodeShloConvolution,
+            Self::ShloSlice => LiteRtOpCode_kLiteRtOpCodeShloSlice,
+            Self::ShloCustomCall => LiteRtOpCode_kLiteRtOpCodeShloCustomCall,
+            Self::ShloReduce => LiteRtOpCode_kLiteRtOpCodeShloReduce,
+            Self::ShloAbs => LiteRtOpCode_kLiteRtOpCodeShloAbs,
+            Self::ShloAnd => LiteRtOpCode_kLiteRtOpCodeShloAnd,
+            Self::ShloCosine => LiteRtOpCode_kLiteRtOpCodeShloCosine,
+            Self::ShloExponential => LiteRtOpCode_kLiteRtOpCodeShloExponential,
+            Self::ShloFloor => LiteRtOpCode_kLiteRtOpCodeShloFloor,
+            Self::ShloLog => LiteRtOpCode_kLiteRtOpCodeShloLog,
+            Self::ShloMinimum => LiteRtOpCode_kLiteRtOpCodeShloMinimum,
+            Self::ShloNegate => LiteRtOpCode_kLiteRtOpCodeShloNegate,
+            Self::ShloOr => LiteRtOpCode_kLiteRtOpCodeShloOr,
+            Self::ShloPower => LiteRtOpCode_kLiteRtOpCodeShloPower,
+            Self::ShloRemainder => LiteRtOpCode_kLiteRtOpCodeShloRemainder,
+            Self::ShloRsqrt => LiteRtOpCode_kLiteRtOpCodeShloRsqrt,
+            Self::ShloSelect => LiteRtOpCode_kLiteRtOpCodeShloSelect,
+            Self::ShloSubtract => LiteRtOpCode_kLiteRtOpCodeShloSubtract,
+            Self::ShloTanh => LiteRtOpCode_kLiteRtOpCodeShloTanh,
+            Self::ShloScatter => LiteRtOpCode_kLiteRtOpCodeShloScatter,
+            Self::ShloCompare => LiteRtOpCode_kLiteRtOpCodeShloCompare,
+            Self::ShloConvert => LiteRtOpCode_kLiteRtOpCodeShloConvert,
+            Self::ShloDynamicSlice => LiteRtOpCode_kLiteRtOpCodeShloDynamicSlice,
+            Self::ShloDynamicUpdateSlice => LiteRtOpCode_kLiteRtOpCodeShloDynamicUpdateSlice,
+            Self::ShloPad => LiteRtOpCode_kLiteRtOpCodeShloPad,
+            Self::ShloIota => LiteRtOpCode_kLiteRtOpCodeShloIota,
+            Self::ShloGeneral => LiteRtOpCode_kLiteRtOpCodeShloGeneral,
+            Self::ShloWindow => LiteRtOpCode_kLiteRtOpCodeShloWindow,
+            Self::ShloSort => LiteRtOpCode_kLiteRtOpCodeShloSort,
+            Self::ShloWhile => LiteRtOpCode_kLiteRtOpCodeShloWhile,
+            Self::ShloGather => LiteRtOpCode_kLiteRtOpCodeShloGather,
+            Self::ShloTranspose => LiteRtOpCode_kLiteRtOpCodeShloTranspose,
+            Self::TflDilate => LiteRtOpCode_kLiteRtOpCodeTflDilate,
+            Self::ShloRngBitGenerator => LiteRtOpCode_kLiteRtOpCodeShloRngBitGenerator,
+            Self::TflReduceWindow => LiteRtOpCode_kLiteRtOpCodeTflReduceWindow,
+            Self::ShloComposite => LiteRtOpCode_kLiteRtOpCodeShloComposite,
+        }
+    }
+
+    pub(crate) fn from_c_enum(enum_value: LiteRtOpCode) -> Result<OpCode, Error> {
+        match enum_value {
+            LiteRtOpCode_kLiteRtOpCodeTflAdd => Ok(Self::TflAdd),
+            LiteRtOpCode_kLiteRtOpCodeTflAveragePool2d => Ok(Self::TflAveragePool2d),
+            LiteRtOpCode_kLiteRtOpCodeTflConcatenation => Ok(Self::TflConcatenation),
+            LiteRtOpCode_kLiteRtOpCodeTflConv2d => Ok(Self::TflConv2d),
+            LiteRtOpCode_kLiteRtOpCodeTflDepthwiseConv2d => Ok(Self::TflDepthwiseConv2d),
+            LiteRtOpCode_kLiteRtOpCodeTflDepthToSpace => Ok(Self::TflDepthToSpace),
+            LiteRtOpCode_kLiteRtOpCodeTflDequantize => Ok(Self::TflDequantize),
+            LiteRtOpCode_kLiteRtOpCodeTflEmbeddingLookup => Ok(Self::TflEmbeddingLookup),
+            LiteRtOpCode_kLiteRtOpCodeTflFloor => Ok(Self::TflFloor),
+            LiteRtOpCode_kLiteRtOpCodeTflFullyConnected => Ok(Self::TflFullyConnected),
+            LiteRtOpCode_kLiteRtOpCodeTflHashtableLookup => Ok(Self::TflHashtableLookup),
+            LiteRtOpCode_kLiteRtOpCodeTflL2Normalization => Ok(Self::TflL2Normalization),
+            LiteRtOpCode_kLiteRtOpCodeTflL2Pool2d => Ok(Self::TflL2Pool2d),
+            LiteRtOpCode_kLiteRtOpCodeTflLocalResponseNormalization => {
+                Ok(Self::TflLocalResponseNormalization)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflLogistic => Ok(Self::TflLogistic),
+            LiteRtOpCode_kLiteRtOpCodeTflLshProjection => Ok(Self::TflLshProjection),
+            LiteRtOpCode_kLiteRtOpCodeTflLstm => Ok(Self::TflLstm),
+            LiteRtOpCode_kLiteRtOpCodeTflMaxPool2d => Ok(Self::TflMaxPool2d),
+            LiteRtOpCode_kLiteRtOpCodeTflMul => Ok(Self::TflMul),
+            LiteRtOpCode_kLiteRtOpCodeTflRelu => Ok(Self::TflRelu),
+            LiteRtOpCode_kLiteRtOpCodeTflReluN1To1 => Ok(Self::TflReluN1To1),
+            LiteRtOpCode_kLiteRtOpCodeTflRelu6 => Ok(Self::TflRelu6),
+            LiteRtOpCode_kLiteRtOpCodeTflReshape => Ok(Self::TflReshape),
+            LiteRtOpCode_kLiteRtOpCodeTflResizeBilinear => Ok(Self::TflResizeBilinear),
+            LiteRtOpCode_kLiteRtOpCodeTflRnn => Ok(Self::TflRnn),
+            LiteRtOpCode_kLiteRtOpCodeTflSoftmax => Ok(Self::TflSoftmax),
+            LiteRtOpCode_kLiteRtOpCodeTflSpaceToDepth => Ok(Self::TflSpaceToDepth),
+            LiteRtOpCode_kLiteRtOpCodeTflSvdf => Ok(Self::TflSvdf),
+            LiteRtOpCode_kLiteRtOpCodeTflTanh => Ok(Self::TflTanh),
+            LiteRtOpCode_kLiteRtOpCodeTflConcatEmbeddings => Ok(Self::TflConcatEmbeddings),
+            LiteRtOpCode_kLiteRtOpCodeTflSkipGram => Ok(Self::TflSkipGram),
+            LiteRtOpCode_kLiteRtOpCodeTflCall => Ok(Self::TflCall),
+            LiteRtOpCode_kLiteRtOpCodeTflCustom => Ok(Self::TflCustom),
+            LiteRtOpCode_kLiteRtOpCodeTflEmbeddingLookupSparse => {
+                Ok(Self::TflEmbeddingLookupSparse)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflPad => Ok(Self::TflPad),
+            LiteRtOpCode_kLiteRtOpCodeTflUnidirectionalSequenceRnn => {
+                Ok(Self::TflUnidirectionalSequenceRnn)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflGather => Ok(Self::TflGather),
+            LiteRtOpCode_kLiteRtOpCodeTflBatchToSpaceNd => Ok(Self::TflBatchToSpaceNd),
+            LiteRtOpCode_kLiteRtOpCodeTflSpaceToBatchNd => Ok(Self::TflSpaceToBatchNd),
+            LiteRtOpCode_kLiteRtOpCodeTflTranspose => Ok(Self::TflTranspose),
+            LiteRtOpCode_kLiteRtOpCodeTflMean => Ok(Self::TflMean),
+            LiteRtOpCode_kLiteRtOpCodeTflSub => Ok(Self::TflSub),
+            LiteRtOpCode_kLiteRtOpCodeTflDiv => Ok(Self::TflDiv),
+            LiteRtOpCode_kLiteRtOpCodeTflSqueeze => Ok(Self::TflSqueeze),
+            LiteRtOpCode_kLiteRtOpCodeTflUnidirectionalSequenceLstm => {
+                Ok(Self::TflUnidirectionalSequenceLstm)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflStridedSlice => Ok(Self::TflStridedSlice),
+            LiteRtOpCode_kLiteRtOpCodeTflBidirectionalSequenceRnn => {
+                Ok(Self::TflBidirectionalSequenceRnn)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflExp => Ok(Self::TflExp),
+            LiteRtOpCode_kLiteRtOpCodeTflTopkV2 => Ok(Self::TflTopkV2),
+            LiteRtOpCode_kLiteRtOpCodeTflSplit => Ok(Self::TflSplit),
+            LiteRtOpCode_kLiteRtOpCodeTflLogSoftmax => Ok(Self::TflLogSoftmax),
+            LiteRtOpCode_kLiteRtOpCodeTflDelegate => Ok(Self::TflDelegate),
+            LiteRtOpCode_kLiteRtOpCodeTflBidirectionalSequenceLstm => {
+                Ok(Self::TflBidirectionalSequenceLstm)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflCast => Ok(Self::TflCast),
+            LiteRtOpCode_kLiteRtOpCodeTflPrelu => Ok(Self::TflPrelu),
+            LiteRtOpCode_kLiteRtOpCodeTflMaximum => Ok(Self::TflMaximum),
+            LiteRtOpCode_kLiteRtOpCodeTflArgMax => Ok(Self::TflArgMax),
+            LiteRtOpCode_kLiteRtOpCodeTflMinimum => Ok(Self::TflMinimum),
+            LiteRtOpCode_kLiteRtOpCodeTflLess => Ok(Self::TflLess),
+            LiteRtOpCode_kLiteRtOpCodeTflNeg => Ok(Self::TflNeg),
+            LiteRtOpCode_kLiteRtOpCodeTflPadv2 => Ok(Self::TflPadv2),
+            LiteRtOpCode_kLiteRtOpCodeTflGreater => Ok(Self::TflGreater),
+            LiteRtOpCode_kLiteRtOpCodeTflGreaterEqual => Ok(Self::TflGreaterEqual),
+            LiteRtOpCode_kLiteRtOpCodeTflLessEqual => Ok(Self::TflLessEqual),
+            LiteRtOpCode_kLiteRtOpCodeTflSelect => Ok(Self::TflSelect),
+            LiteRtOpCode_kLiteRtOpCodeTflSlice => Ok(Self::TflSlice),
+            LiteRtOpCode_kLiteRtOpCodeTflSin => Ok(Self::TflSin),
+            LiteRtOpCode_kLiteRtOpCodeTflTransposeConv => Ok(Self::TflTransposeConv),
+            LiteRtOpCode_kLiteRtOpCodeTflSparseToDense => Ok(Self::TflSparseToDense),
+            LiteRtOpCode_kLiteRtOpCodeTflTile => Ok(Self::TflTile),
+            LiteRtOpCode_kLiteRtOpCodeTflExpandDims => Ok(Self::TflExpandDims),
+            LiteRtOpCode_kLiteRtOpCodeTflEqual => Ok(Self::TflEqual),
+            LiteRtOpCode_kLiteRtOpCodeTflNotEqual => Ok(Self::TflNotEqual),
+            LiteRtOpCode_kLiteRtOpCodeTflLog => Ok(Self::TflLog),
+            LiteRtOpCode_kLiteRtOpCodeTflSum => Ok(Self::TflSum),
+            LiteRtOpCode_kLiteRtOpCodeTflSqrt => Ok(Self::TflSqrt),
+            LiteRtOpCode_kLiteRtOpCodeTflRsqrt => Ok(Self::TflRsqrt),
+            LiteRtOpCode_kLiteRtOpCodeTflShape => Ok(Self::TflShape),
+            LiteRtOpCode_kLiteRtOpCodeTflPow => Ok(Self::TflPow),
+            LiteRtOpCode_kLiteRtOpCodeTflArgMin => Ok(Self::TflArgMin),
+            LiteRtOpCode_kLiteRtOpCodeTflFakeQuant => Ok(Self::TflFakeQuant),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceProd => Ok(Self::TflReduceProd),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceMax => Ok(Self::TflReduceMax),
+            LiteRtOpCode_kLiteRtOpCodeTflPack => Ok(Self::TflPack),
+            LiteRtOpCode_kLiteRtOpCodeTflLogicalOr => Ok(Self::TflLogicalOr),
+            LiteRtOpCode_kLiteRtOpCodeTflOneHot => Ok(Self::TflOneHot),
+            LiteRtOpCode_kLiteRtOpCodeTflLogicalAnd => Ok(Self::TflLogicalAnd),
+            LiteRtOpCode_kLiteRtOpCodeTflLogicalNot => Ok(Self::TflLogicalNot),
+            LiteRtOpCode_kLiteRtOpCodeTflUnpack => Ok(Self::TflUnpack),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceMin => Ok(Self::TflReduceMin),
+            LiteRtOpCode_kLiteRtOpCodeTflFloorDiv => Ok(Self::TflFloorDiv),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceAny => Ok(Self::TflReduceAny),
+            LiteRtOpCode_kLiteRtOpCodeTflSquare => Ok(Self::TflSquare),
+            LiteRtOpCode_kLiteRtOpCodeTflZerosLike => Ok(Self::TflZerosLike),
+            LiteRtOpCode_kLiteRtOpCodeTflFill => Ok(Self::TflFill),
+            LiteRtOpCode_kLiteRtOpCodeTflFloorMod => Ok(Self::TflFloorMod),
+            LiteRtOpCode_kLiteRtOpCodeTflRange => Ok(Self::TflRange),
+            LiteRtOpCode_kLiteRtOpCodeTflResizeNearestNeighbor => {
+                Ok(Self::TflResizeNearestNeighbor)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflLeakyRelu => Ok(Self::TflLeakyRelu),
+            LiteRtOpCode_kLiteRtOpCodeTflSquaredDifference => Ok(Self::TflSquaredDifference),
+            LiteRtOpCode_kLiteRtOpCodeTflMirrorPad => Ok(Self::TflMirrorPad),
+            LiteRtOpCode_kLiteRtOpCodeTflAbs => Ok(Self::TflAbs),
+            LiteRtOpCode_kLiteRtOpCodeTflSplitV => Ok(Self::TflSplitV),
+            LiteRtOpCode_kLiteRtOpCodeTflUnique => Ok(Self::TflUnique),
+            LiteRtOpCode_kLiteRtOpCodeTflCeil => Ok(Self::TflCeil),
+            LiteRtOpCode_kLiteRtOpCodeTflReverseV2 => Ok(Self::TflReverseV2),
+            LiteRtOpCode_kLiteRtOpCodeTflAddN => Ok(Self::TflAddN),
+            LiteRtOpCode_kLiteRtOpCodeTflGatherNd => Ok(Self::TflGatherNd),
+            LiteRtOpCode_kLiteRtOpCodeTflCos => Ok(Self::TflCos),
+            LiteRtOpCode_kLiteRtOpCodeTflWhere => Ok(Self::TflWhere),
+            LiteRtOpCode_kLiteRtOpCodeTflRank => Ok(Self::TflRank),
+            LiteRtOpCode_kLiteRtOpCodeTflElu => Ok(Self::TflElu),
+            LiteRtOpCode_kLiteRtOpCodeTflReverseSequence => Ok(Self::TflReverseSequence),
+            LiteRtOpCode_kLiteRtOpCodeTflMatrixDiag => Ok(Self::TflMatrixDiag),
+            LiteRtOpCode_kLiteRtOpCodeTflQuantize => Ok(Self::TflQuantize),
+            LiteRtOpCode_kLiteRtOpCodeTflMatrixSetDiag => Ok(Self::TflMatrixSetDiag),
+            LiteRtOpCode_kLiteRtOpCodeTflRound => Ok(Self::TflRound),
+            LiteRtOpCode_kLiteRtOpCodeTflHardSwish => Ok(Self::TflHardSwish),
+            LiteRtOpCode_kLiteRtOpCodeTflIf => Ok(Self::TflIf),
+            LiteRtOpCode_kLiteRtOpCodeTflWhile => Ok(Self::TflWhile),
+            LiteRtOpCode_kLiteRtOpCodeTflNonMaxSuppressionV4 => Ok(Self::TflNonMaxSuppressionV4),
+            LiteRtOpCode_kLiteRtOpCodeTflNonMaxSuppressionV5 => Ok(Self::TflNonMaxSuppressionV5),
+            LiteRtOpCode_kLiteRtOpCodeTflScatterNd => Ok(Self::TflScatterNd),
+            LiteRtOpCode_kLiteRtOpCodeTflSelectV2 => Ok(Self::TflSelectV2),
+            LiteRtOpCode_kLiteRtOpCodeTflDensify => Ok(Self::TflDensify),
+            LiteRtOpCode_kLiteRtOpCodeTflSegmentSum => Ok(Self::TflSegmentSum),
+            LiteRtOpCode_kLiteRtOpCodeTflBatchMatmul => Ok(Self::TflBatchMatmul),
+            LiteRtOpCode_kLiteRtOpCodeTflPlaceholderForGreaterOpCodeTfls => {
+                Ok(Self::TflPlaceholderForGreaterOpCodeTfls)
+            }
+            LiteRtOpCode_kLiteRtOpCodeTflCumsum => Ok(Self::TflCumsum),
+            LiteRtOpCode_kLiteRtOpCodeTflCallOnce => Ok(Self::TflCallOnce),
+            LiteRtOpCode_kLiteRtOpCodeTflBroadcastTo => Ok(Self::TflBroadcastTo),
+            LiteRtOpCode_kLiteRtOpCodeTflRfft2d => Ok(Self::TflRfft2d),
+            LiteRtOpCode_kLiteRtOpCodeTflConv3d => Ok(Self::TflConv3d),
+            LiteRtOpCode_kLiteRtOpCodeTflImag => Ok(Self::TflImag),
+            LiteRtOpCode_kLiteRtOpCodeTflReal => Ok(Self::TflReal),
+            LiteRtOpCode_kLiteRtOpCodeTflComplexAbs => Ok(Self::TflComplexAbs),
+            LiteRtOpCode_kLiteRtOpCodeTflHashtable => Ok(Self::TflHashtable),
+            LiteRtOpCode_kLiteRtOpCodeTflHashtableFind => Ok(Self::TflHashtableFind),
+            LiteRtOpCode_kLiteRtOpCodeTflHashtableImport => Ok(Self::TflHashtableImport),
+            LiteRtOpCode_kLiteRtOpCodeTflHashtableSize => Ok(Self::TflHashtableSize),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceAll => Ok(Self::TflReduceAll),
+            LiteRtOpCode_kLiteRtOpCodeTflConv3dTranspose => Ok(Self::TflConv3dTranspose),
+            LiteRtOpCode_kLiteRtOpCodeTflVarHandle => Ok(Self::TflVarHandle),
+            LiteRtOpCode_kLiteRtOpCodeTflReadVariable => Ok(Self::TflReadVariable),
+            LiteRtOpCode_kLiteRtOpCodeTflAssignVariable => Ok(Self::TflAssignVariable),
+            LiteRtOpCode_kLiteRtOpCodeTflBroadcastArgs => Ok(Self::TflBroadcastArgs),
+            LiteRtOpCode_kLiteRtOpCodeTflRandomStandardNormal => Ok(Self::TflRandomStandardNormal),
+            LiteRtOpCode_kLiteRtOpCodeTflBucketize => Ok(Self::TflBucketize),
+            LiteRtOpCode_kLiteRtOpCodeTflRandomUniform => Ok(Self::TflRandomUniform),
+            LiteRtOpCode_kLiteRtOpCodeTflMultinomial => Ok(Self::TflMultinomial),
+            LiteRtOpCode_kLiteRtOpCodeTflGelu => Ok(Self::TflGelu),
+            LiteRtOpCode_kLiteRtOpCodeTflDynamicUpdateSlice => Ok(Self::TflDynamicUpdateSlice),
+            LiteRtOpCode_kLiteRtOpCodeTflRelu0To1 => Ok(Self::TflRelu0To1),
+            LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentProd => Ok(Self::TflUnsortedSegmentProd),
+            LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentMax => Ok(Self::TflUnsortedSegmentMax),
+            LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentSum => Ok(Self::TflUnsortedSegmentSum),
+            LiteRtOpCode_kLiteRtOpCodeTflAtan2 => Ok(Self::TflAtan2),
+            LiteRtOpCode_kLiteRtOpCodeTflUnsortedSegmentMin => Ok(Self::TflUnsortedSegmentMin),
+            LiteRtOpCode_kLiteRtOpCodeTflSign => Ok(Self::TflSign),
+            LiteRtOpCode_kLiteRtOpCodeTflBitcast => Ok(Self::TflBitcast),
+            LiteRtOpCode_kLiteRtOpCodeTflBitwiseXor => Ok(Self::TflBitwiseXor),
+            LiteRtOpCode_kLiteRtOpCodeTflRightShift => Ok(Self::TflRightShift),
+            LiteRtOpCode_kLiteRtOpCodeShloLogistic => Ok(Self::ShloLogistic),
+            LiteRtOpCode_kLiteRtOpCodeShloAdd => Ok(Self::ShloAdd),
+            LiteRtOpCode_kLiteRtOpCodeShloDivide => Ok(Self::ShloDivide),
+            LiteRtOpCode_kLiteRtOpCodeShloMultiply => Ok(Self::ShloMultiply),
+            LiteRtOpCode_kLiteRtOpCodeShloMaximum => Ok(Self::ShloMaximum),
+            LiteRtOpCode_kLiteRtOpCodeShloReshape => Ok(Self::ShloReshape),
+            LiteRtOpCode_kLiteRtOpCodeShloClamp => Ok(Self::ShloClamp),
+            LiteRtOpCode_kLiteRtOpCodeShloConcatenate => Ok(Self::ShloConcatenate),
+            LiteRtOpCode_kLiteRtOpCodeShloBroadcastInDim => Ok(Self::ShloBroadcastInDim),
+            LiteRtOpCode_kLiteRtOpCodeShloConvolution => Ok(Self::ShloConvolution),
+            LiteRtOpCode_kLiteRtOpCodeShloSlice => Ok(Self::ShloSlice),
+            LiteRtOpCode_kLiteRtOpCodeShloCustomCall => Ok(Self::ShloCustomCall),
+            LiteRtOpCode_kLiteRtOpCodeShloReduce => Ok(Self::ShloReduce),
+            LiteRtOpCode_kLiteRtOpCodeShloAbs => Ok(Self::ShloAbs),
+            LiteRtOpCode_kLiteRtOpCodeShloAnd => Ok(Self::ShloAnd),
+            LiteRtOpCode_kLiteRtOpCodeShloCosine => Ok(Self::ShloCosine),
+            LiteRtOpCode_kLiteRtOpCodeShloExponential => Ok(Self::ShloExponential),
+            LiteRtOpCode_kLiteRtOpCodeShloFloor => Ok(Self::ShloFloor),
+            LiteRtOpCode_kLiteRtOpCodeShloLog => Ok(Self::ShloLog),
+            LiteRtOpCode_kLiteRtOpCodeShloMinimum => Ok(Self::ShloMinimum),
+            LiteRtOpCode_kLiteRtOpCodeShloNegate => Ok(Self::ShloNegate),
+            LiteRtOpCode_kLiteRtOpCodeShloOr => Ok(Self::ShloOr),
+            LiteRtOpCode_kLiteRtOpCodeShloPower => Ok(Self::ShloPower),
+            LiteRtOpCode_kLiteRtOpCodeShloRemainder => Ok(Self::ShloRemainder),
+            LiteRtOpCode_kLiteRtOpCodeShloRsqrt => Ok(Self::ShloRsqrt),
+            LiteRtOpCode_kLiteRtOpCodeShloSelect => Ok(Self::ShloSelect),
+            LiteRtOpCode_kLiteRtOpCodeShloSubtract => Ok(Self::ShloSubtract),
+            LiteRtOpCode_kLiteRtOpCodeShloTanh => Ok(Self::ShloTanh),
+            LiteRtOpCode_kLiteRtOpCodeShloScatter => Ok(Self::ShloScatter),
+            LiteRtOpCode_kLiteRtOpCodeShloCompare => Ok(Self::ShloCompare),
+            LiteRtOpCode_kLiteRtOpCodeShloConvert => Ok(Self::ShloConvert),
+            LiteRtOpCode_kLiteRtOpCodeShloDynamicSlice => Ok(Self::ShloDynamicSlice),
+            LiteRtOpCode_kLiteRtOpCodeShloDynamicUpdateSlice => Ok(Self::ShloDynamicUpdateSlice),
+            LiteRtOpCode_kLiteRtOpCodeShloPad => Ok(Self::ShloPad),
+            LiteRtOpCode_kLiteRtOpCodeShloIota => Ok(Self::ShloIota),
+            LiteRtOpCode_kLiteRtOpCodeShloGeneral => Ok(Self::ShloGeneral),
+            LiteRtOpCode_kLiteRtOpCodeShloWindow => Ok(Self::ShloWindow),
+            LiteRtOpCode_kLiteRtOpCodeShloSort => Ok(Self::ShloSort),
+            LiteRtOpCode_kLiteRtOpCodeShloWhile => Ok(Self::ShloWhile),
+            LiteRtOpCode_kLiteRtOpCodeShloGather => Ok(Self::ShloGather),
+            LiteRtOpCode_kLiteRtOpCodeShloTranspose => Ok(Self::ShloTranspose),
+            LiteRtOpCode_kLiteRtOpCodeTflDilate => Ok(Self::TflDilate),
+            LiteRtOpCode_kLiteRtOpCodeShloRngBitGenerator => Ok(Self::ShloRngBitGenerator),
+            LiteRtOpCode_kLiteRtOpCodeTflReduceWindow => Ok(Self::TflReduceWindow),
+            LiteRtOpCode_kLiteRtOpCodeShloComposite => Ok(Self::ShloComposite),
+            _ => Err(Error::new(
+                ErrorCause::InvalidOpCodeEnumValue,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_op_code_round_trip() {
+        for op_code in [OpCode::TflAdd, OpCode::TflConv2d, OpCode::ShloComposite] {
+            assert_eq!(OpCode::from_c_enum(op_code.to_c_enum()), Ok(op_code));
+        }
+        assert_eq!(
+            OpCode::from_c_enum(LiteRtOpCode_kLiteRtOpCodeTflCustom),
+            Ok(OpCode::TflCustom)
+        );
+        assert!(OpCode::from_c_enum(1000).is_err());
+    }
+}
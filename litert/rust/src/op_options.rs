@@ -0,0 +1,934 @@
+//! Typed options of the builtin operations.
+//!
+//! The options are read through the `LiteRtGet*Option` functions of `litert_op_options.h` and
+//! returned as plain Rust structs. Use `Op::options()` to get the options of an operation.
+#![allow(non_upper_case_globals)]
+
+use crate::bindings::*;
+use crate::call_check_status;
+use crate::error::{Error, ErrorCause};
+use crate::helper_funs::c_str_to_str;
+use crate::model::{Model, Op, Subgraph};
+use crate::op_code::OpCode;
+use crate::ElementType;
+use std::ffi::c_char;
+
+/// Padding scheme of convolution and pooling operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Padding {
+    Same,
+    Valid,
+}
+
+impl Padding {
+    pub(crate) fn from_c_value(value: u32) -> Result<Padding, Error> {
+        match value {
+            0 => Ok(Self::Same),
+            1 => Ok(Self::Valid),
+            _ => Err(Error::new(
+                ErrorCause::InvalidPaddingValue,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+}
+
+/// Activation function fused into an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FusedActivation {
+    None,
+    Relu,
+    ReluN1To1,
+    Relu6,
+    Tanh,
+    SignBit,
+}
+
+impl FusedActivation {
+    pub(crate) fn from_c_value(value: u32) -> Result<FusedActivation, Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Relu),
+            2 => Ok(Self::ReluN1To1),
+            3 => Ok(Self::Relu6),
+            4 => Ok(Self::Tanh),
+            5 => Ok(Self::SignBit),
+            _ => Err(Error::new(
+                ErrorCause::InvalidFusedActivationValue,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+}
+
+/// Padding mode of the MirrorPad operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MirrorPadMode {
+    Reflect,
+    Symmetric,
+}
+
+impl MirrorPadMode {
+    pub(crate) fn from_c_value(value: u32) -> Result<MirrorPadMode, Error> {
+        match value {
+            0 => Ok(Self::Reflect),
+            1 => Ok(Self::Symmetric),
+            _ => Err(Error::new(
+                ErrorCause::InvalidMirrorPadModeValue,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+}
+
+/// Layout of the weights of the FullyConnected operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum WeightsFormat {
+    Default,
+    Shuffled4x16Int8,
+}
+
+impl WeightsFormat {
+    pub(crate) fn from_c_value(value: u32) -> Result<WeightsFormat, Error> {
+        match value {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::Shuffled4x16Int8),
+            _ => Err(Error::new(
+                ErrorCause::InvalidWeightsFormatValue,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+}
+
+/// Options of the Add, Sub, Mul and Div operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ElementwiseOptions {
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the BatchMatmul operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BatchMatmulOptions {
+    pub adj_x: bool,
+    pub adj_y: bool,
+    pub asymmetric_quantize_input: bool,
+}
+
+/// Options of the Concatenation operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ConcatenationOptions {
+    pub axis: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the FullyConnected operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FullyConnectedOptions {
+    pub fused_activation: FusedActivation,
+    pub weights_format: WeightsFormat,
+    pub keep_num_dims: bool,
+    pub quantized_bias_type: ElementType,
+    pub asymmetric_quantize_input: bool,
+}
+
+/// Options of the Softmax operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SoftmaxOptions {
+    pub beta: f32,
+}
+
+/// Options of the StridedSlice operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StridedSliceOptions {
+    pub begin_mask: i32,
+    pub end_mask: i32,
+    pub ellipsis_mask: i32,
+    pub new_axis_mask: i32,
+    pub shrink_axis_mask: i32,
+    pub offset: bool,
+}
+
+/// Options of the Reshape operation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ReshapeOptions {
+    pub new_shape: Vec<i32>,
+}
+
+/// Options of the Sum, Mean, ReduceMax, ReduceMin, ReduceAny and ReduceAll operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ReducerOptions {
+    pub keep_dims: bool,
+}
+
+/// Options of the Pack operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PackOptions {
+    pub axis: i32,
+    pub values_count: i32,
+}
+
+/// Options of the Unpack operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UnpackOptions {
+    pub axis: i32,
+    pub num: i32,
+}
+
+/// Options of the Gather operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GatherOptions {
+    pub axis: i32,
+    pub batch_dims: i32,
+}
+
+/// Options of the Split operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SplitOptions {
+    pub num_splits: i32,
+}
+
+/// Options of the Conv2d operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Conv2dOptions {
+    pub padding: Padding,
+    pub stride_h: i32,
+    pub stride_w: i32,
+    pub dilation_h: i32,
+    pub dilation_w: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the Conv3d operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Conv3dOptions {
+    pub padding: Padding,
+    pub stride_d: i32,
+    pub stride_h: i32,
+    pub stride_w: i32,
+    pub dilation_d: i32,
+    pub dilation_h: i32,
+    pub dilation_w: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the DepthwiseConv2d operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DepthwiseConv2dOptions {
+    pub padding: Padding,
+    pub stride_h: i32,
+    pub stride_w: i32,
+    pub depth_multiplier: i32,
+    pub dilation_h: i32,
+    pub dilation_w: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the TransposeConv operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TransposeConvOptions {
+    pub padding: Padding,
+    pub stride_h: i32,
+    pub stride_w: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the AveragePool2d, MaxPool2d and L2Pool2d operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Pool2dOptions {
+    pub padding: Padding,
+    pub stride_h: i32,
+    pub stride_w: i32,
+    pub filter_height: i32,
+    pub filter_width: i32,
+    pub fused_activation: FusedActivation,
+}
+
+/// Options of the ResizeBilinear and ResizeNearestNeighbor operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ResizeOptions {
+    pub align_corners: bool,
+    pub half_pixel_centers: bool,
+}
+
+/// Options of the LeakyRelu operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LeakyReluOptions {
+    pub alpha: f32,
+}
+
+/// Options of the DepthToSpace and SpaceToDepth operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BlockSizeOptions {
+    pub block_size: i32,
+}
+
+/// Options of the Cumsum operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CumsumOptions {
+    pub exclusive: bool,
+    pub reverse: bool,
+}
+
+/// Options of the Gelu operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GeluOptions {
+    pub approximate: bool,
+}
+
+/// Options of the MirrorPad operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MirrorPadOptions {
+    pub mode: MirrorPadMode,
+}
+
+/// Options of the Squeeze operation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SqueezeOptions {
+    pub squeeze_dims: Vec<i32>,
+}
+
+/// Options of an operation.
+///
+/// Every variant corresponds to an operation code, `None` is returned for operations that don't
+/// have options accessible through the C API.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OpOptions {
+    None,
+    Add(ElementwiseOptions),
+    Sub(ElementwiseOptions),
+    Mul(ElementwiseOptions),
+    Div(ElementwiseOptions),
+    BatchMatmul(BatchMatmulOptions),
+    Concatenation(ConcatenationOptions),
+    FullyConnected(FullyConnectedOptions),
+    Softmax(SoftmaxOptions),
+    StridedSlice(StridedSliceOptions),
+    Reshape(ReshapeOptions),
+    Sum(ReducerOptions),
+    Mean(ReducerOptions),
+    ReduceMax(ReducerOptions),
+    ReduceMin(ReducerOptions),
+    ReduceAny(ReducerOptions),
+    ReduceAll(ReducerOptions),
+    Pack(PackOptions),
+    Unpack(UnpackOptions),
+    Gather(GatherOptions),
+    Split(SplitOptions),
+    Conv2d(Conv2dOptions),
+    Conv3d(Conv3dOptions),
+    DepthwiseConv2d(DepthwiseConv2dOptions),
+    TransposeConv(TransposeConvOptions),
+    AveragePool2d(Pool2dOptions),
+    MaxPool2d(Pool2dOptions),
+    L2Pool2d(Pool2dOptions),
+    ResizeBilinear(ResizeOptions),
+    ResizeNearestNeighbor(ResizeOptions),
+    LeakyRelu(LeakyReluOptions),
+    DepthToSpace(BlockSizeOptions),
+    SpaceToDepth(BlockSizeOptions),
+    Cumsum(CumsumOptions),
+    Gelu(GeluOptions),
+    MirrorPad(MirrorPadOptions),
+    Squeeze(SqueezeOptions),
+}
+
+/// A StableHLO composite operation.
+///
+/// The composite is a named group of operations with a decomposition subgraph that implements it,
+/// backends can lower the whole composite natively instead of running the decomposition.
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeOp<'a> {
+    /// Name of the composite, e.g. `odml.l2_norm`.
+    pub name: &'a str,
+    /// Version of the composite.
+    pub version: i32,
+    /// Attributes of the composite, a FlexBuffer encoded map.
+    pub attributes: &'a [u8],
+    /// Index of the decomposition subgraph in the model.
+    pub decomposition_subgraph_index: LiteRtParamIndex,
+}
+
+impl CompositeOp<'_> {
+    /// Returns the subgraph implementing the composite.
+    ///
+    /// `model` must be the model the composite operation belongs to.
+    pub fn decomposition<'m>(&self, model: &'m Model) -> Result<Subgraph<'m>, Error> {
+        model.subgraph(self.decomposition_subgraph_index)
+    }
+}
+
+type OptionGetter<T> = unsafe extern "C" fn(LiteRtOp, *mut T) -> LiteRtStatus;
+
+// Reads a single scalar option with one of the LiteRtGet*Option functions.
+fn get_option<T: Default>(
+    raw_op: LiteRtOp,
+    getter: OptionGetter<T>,
+    error_cause: ErrorCause,
+) -> Result<T, Error> {
+    let mut value = T::default();
+    call_check_status!(
+        // SAFETY: raw_op is always valid as it's taken from an Op wrapper.
+        // We assume that the output is valid if the return status is OK or don't use the output pointer.
+        unsafe { getter(raw_op, &mut value) },
+        error_cause
+    );
+    Ok(value)
+}
+
+// Maps the TFLite schema TensorType stored in the options to the element type.
+fn element_type_from_tflite_tensor_type(value: u32) -> Result<ElementType, Error> {
+    match value {
+        0 => Ok(ElementType::Float32),
+        1 => Ok(ElementType::Float16),
+        2 => Ok(ElementType::Int32),
+        3 => Ok(ElementType::UInt8),
+        4 => Ok(ElementType::Int64),
+        5 => Ok(ElementType::TfString),
+        6 => Ok(ElementType::Bool),
+        7 => Ok(ElementType::Int16),
+        8 => Ok(ElementType::Complex64),
+        9 => Ok(ElementType::Int8),
+        10 => Ok(ElementType::Float64),
+        11 => Ok(ElementType::Complex128),
+        12 => Ok(ElementType::UInt64),
+        13 => Ok(ElementType::TfResource),
+        14 => Ok(ElementType::TfVariant),
+        15 => Ok(ElementType::UInt32),
+        16 => Ok(ElementType::UInt16),
+        17 => Ok(ElementType::Int4),
+        18 => Ok(ElementType::BFloat16),
+        _ => Err(Error::new(
+            ErrorCause::InvalidElementTypeEnumValue,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        )),
+    }
+}
+
+impl<'a> Op<'a> {
+    /// Returns the composite description if the operation is a StableHLO composite.
+    pub fn as_composite(&self) -> Result<Option<CompositeOp<'a>>, Error> {
+        if self.code()? != OpCode::ShloComposite {
+            return Ok(None);
+        }
+        let op = self.raw_op;
+        let cause = ErrorCause::GetCompositeOptions;
+        let mut name: *const c_char = std::ptr::null();
+        call_check_status!(
+            // SAFETY: op is always valid as it's taken from an Op wrapper.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetSHLOCompositeOpName(op, &mut name) },
+            cause
+        );
+        // SAFETY: We assume that if C API returns OK then the output is valid, the name is owned
+        // by the model.
+        let name = unsafe { c_str_to_str(name) }?;
+        let mut attributes: *const u8 = std::ptr::null();
+        let mut attributes_size: i32 = 0;
+        call_check_status!(
+            // SAFETY: op is always valid as it's taken from an Op wrapper.
+            // We assume that the output is valid if the return status is OK or don't use the output pointers.
+            unsafe {
+                LiteRtGetSHLOCompositeOpAttributes(op, &mut attributes, &mut attributes_size)
+            },
+            cause
+        );
+        let attributes = if attributes.is_null() || attributes_size <= 0 {
+            &[]
+        } else {
+            // SAFETY: The C API returns `attributes_size` bytes owned by the model.
+            unsafe { std::slice::from_raw_parts(attributes, attributes_size as usize) }
+        };
+        let decomposition_subgraph_index: i32 = get_option(
+            op,
+            LiteRtGetSHLOCompositeOpDecompositionSubgraphIndex,
+            cause,
+        )?;
+        Ok(Some(CompositeOp {
+            name,
+            version: get_option(op, LiteRtGetSHLOCompositeOpVersion, cause)?,
+            attributes,
+            decomposition_subgraph_index: LiteRtParamIndex::try_from(decomposition_subgraph_index)
+                .map_err(|_| Error::new(cause, LiteRtStatus_kLiteRtStatusErrorInvalidArgument))?,
+        }))
+    }
+
+    /// Returns the typed options of the operation.
+    pub fn options(&self) -> Result<OpOptions, Error> {
+        let op = self.raw_op;
+        let options = match self.code()? {
+            OpCode::TflAdd => OpOptions::Add(ElementwiseOptions {
+                fused_activation: FusedActivation::from_c_value(get_option(
+                    op,
+                    LiteRtGetAddFusedActivationOption,
+                    ErrorCause::GetAddOptions,
+                )?)?,
+            }),
+            OpCode::TflSub => OpOptions::Sub(ElementwiseOptions {
+                fused_activation: FusedActivation::from_c_value(get_option(
+                    op,
+                    LiteRtGetSubFusedActivationOption,
+                    ErrorCause::GetSubOptions,
+                )?)?,
+            }),
+            OpCode::TflMul => OpOptions::Mul(ElementwiseOptions {
+                fused_activation: FusedActivation::from_c_value(get_option(
+                    op,
+                    LiteRtGetMulFusedActivationOption,
+                    ErrorCause::GetMulOptions,
+                )?)?,
+            }),
+            OpCode::TflDiv => OpOptions::Div(ElementwiseOptions {
+                fused_activation: FusedActivation::from_c_value(get_option(
+                    op,
+                    LiteRtGetDivFusedActivationOption,
+                    ErrorCause::GetDivOptions,
+                )?)?,
+            }),
+            OpCode::TflBatchMatmul => {
+                let cause = ErrorCause::GetBatchMatmulOptions;
+                OpOptions::BatchMatmul(BatchMatmulOptions {
+                    adj_x: get_option(op, LiteRtGetBatchMatmulAdjXOption, cause)?,
+                    adj_y: get_option(op, LiteRtGetBatchMatmulAdjYOption, cause)?,
+                    asymmetric_quantize_input: get_option(
+                        op,
+                        LiteRtGetBatchMatmulAsymmetricQuantizeInputOption,
+                        cause,
+                    )?,
+                })
+            }
+            OpCode::TflConcatenation => {
+                let cause = ErrorCause::GetConcatenationOptions;
+                OpOptions::Concatenation(ConcatenationOptions {
+                    axis: get_option(op, LiteRtGetConcatenationAxisOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetConcatenationFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflFullyConnected => {
+                let cause = ErrorCause::GetFullyConnectedOptions;
+                OpOptions::FullyConnected(FullyConnectedOptions {
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetFullyConnectedFusedActivationOption,
+                        cause,
+                    )?)?,
+                    weights_format: WeightsFormat::from_c_value(get_option(
+                        op,
+                        LiteRtGetFullyConnectedWeightsFormatOption,
+                        cause,
+                    )?)?,
+                    keep_num_dims: get_option(op, LiteRtGetFullyConnectedKeepNumDimsOption, cause)?,
+                    quantized_bias_type: element_type_from_tflite_tensor_type(get_option(
+                        op,
+                        LiteRtFullyConnectedGetQuantizedBiasTypeOption,
+                        cause,
+                    )?)?,
+                    asymmetric_quantize_input: get_option(
+                        op,
+                        LiteRtGetFullyConnectedAsymmetricQuantizeInputOption,
+                        cause,
+                    )?,
+                })
+            }
+            OpCode::TflSoftmax => OpOptions::Softmax(SoftmaxOptions {
+                beta: get_option(
+                    op,
+                    LiteRtGetSoftmaxBetaOption,
+                    ErrorCause::GetSoftmaxOptions,
+                )?,
+            }),
+            OpCode::TflStridedSlice => {
+                let cause = ErrorCause::GetStridedSliceOptions;
+                OpOptions::StridedSlice(StridedSliceOptions {
+                    begin_mask: get_option(op, LiteRtGetStridedSliceBeginMaskOption, cause)?,
+                    end_mask: get_option(op, LiteRtGetStridedSliceEndMaskOption, cause)?,
+                    ellipsis_mask: get_option(op, LiteRtGetStridedSliceEllipsisMaskOption, cause)?,
+                    new_axis_mask: get_option(op, LiteRtGetStridedSliceNewAxisMaskOption, cause)?,
+                    shrink_axis_mask: get_option(
+                        op,
+                        LiteRtGetStridedSliceShrinkAxisMaskOption,
+                        cause,
+                    )?,
+                    offset: get_option(op, LiteRtGetStridedSliceOffsetOption, cause)?,
+                })
+            }
+            OpCode::TflReshape => {
+                let mut new_shape: *const i32 = std::ptr::null();
+                let mut new_shape_size: i32 = 0;
+                call_check_status!(
+                    // SAFETY: op is always valid as it's taken from an Op wrapper.
+                    // We assume that the output is valid if the return status is OK or don't use the output pointers.
+                    unsafe {
+                        LiteRtGetReshapeNewShapeOption(op, &mut new_shape, &mut new_shape_size)
+                    },
+                    ErrorCause::GetReshapeOptions
+                );
+                OpOptions::Reshape(ReshapeOptions {
+                    // SAFETY: The C API returns a pointer to `new_shape_size` elements owned by the
+                    // model.
+                    new_shape: unsafe { i32_array_to_vec(new_shape, new_shape_size) },
+                })
+            }
+            OpCode::TflSum => OpOptions::Sum(ReducerOptions {
+                keep_dims: get_option(op, LiteRtGetSumKeepDimsOption, ErrorCause::GetSumOptions)?,
+            }),
+            OpCode::TflMean => OpOptions::Mean(ReducerOptions {
+                keep_dims: get_option(op, LiteRtGetMeanKeepDimsOption, ErrorCause::GetMeanOptions)?,
+            }),
+            OpCode::TflReduceMax => OpOptions::ReduceMax(ReducerOptions {
+                keep_dims: get_option(
+                    op,
+                    LiteRtGetReduceMaxKeepDimsOption,
+                    ErrorCause::GetReduceMaxOptions,
+                )?,
+            }),
+            OpCode::TflReduceMin => OpOptions::ReduceMin(ReducerOptions {
+                keep_dims: get_option(
+                    op,
+                    LiteRtGetReduceMinKeepDimsOption,
+                    ErrorCause::GetReduceMinOptions,
+                )?,
+            }),
+            OpCode::TflReduceAny => OpOptions::ReduceAny(ReducerOptions {
+                keep_dims: get_option(
+                    op,
+                    LiteRtGetReduceAnyKeepDimsOption,
+                    ErrorCause::GetReduceAnyOptions,
+                )?,
+            }),
+            OpCode::TflReduceAll => OpOptions::ReduceAll(ReducerOptions {
+                keep_dims: get_option(
+                    op,
+                    LiteRtGetReduceAllKeepDimsOption,
+                    ErrorCause::GetReduceAllOptions,
+                )?,
+            }),
+            OpCode::TflPack => {
+                let cause = ErrorCause::GetPackOptions;
+                OpOptions::Pack(PackOptions {
+                    axis: get_option(op, LiteRtGetPackAxisOption, cause)?,
+                    values_count: get_option(op, LiteRtGetPackValuesCountOption, cause)?,
+                })
+            }
+            OpCode::TflUnpack => {
+                let cause = ErrorCause::GetUnpackOptions;
+                OpOptions::Unpack(UnpackOptions {
+                    axis: get_option(op, LiteRtGetUnpackAxisOption, cause)?,
+                    num: get_option(op, LiteRtGetUnpackNumOption, cause)?,
+                })
+            }
+            OpCode::TflGather => {
+                let cause = ErrorCause::GetGatherOptions;
+                OpOptions::Gather(GatherOptions {
+                    axis: get_option(op, LiteRtGetGatherAxisOption, cause)?,
+                    batch_dims: get_option(op, LiteRtGetGatherBatchDimsOption, cause)?,
+                })
+            }
+            OpCode::TflSplit => OpOptions::Split(SplitOptions {
+                num_splits: get_option(
+                    op,
+                    LiteRtGetSplitNumSplitsOption,
+                    ErrorCause::GetSplitOptions,
+                )?,
+            }),
+            OpCode::TflConv2d => {
+                let cause = ErrorCause::GetConv2dOptions;
+                OpOptions::Conv2d(Conv2dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetConv2dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetConv2dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetConv2dStrideWOption, cause)?,
+                    dilation_h: get_option(op, LiteRtGetConv2dDilationHOption, cause)?,
+                    dilation_w: get_option(op, LiteRtGetConv2dDilationWOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetConv2dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflConv3d => {
+                let cause = ErrorCause::GetConv3dOptions;
+                OpOptions::Conv3d(Conv3dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetConv3dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_d: get_option(op, LiteRtGetConv3dStrideDOption, cause)?,
+                    stride_h: get_option(op, LiteRtGetConv3dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetConv3dStrideWOption, cause)?,
+                    dilation_d: get_option(op, LiteRtGetConv3dDilationDOption, cause)?,
+                    dilation_h: get_option(op, LiteRtGetConv3dDilationHOption, cause)?,
+                    dilation_w: get_option(op, LiteRtGetConv3dDilationWOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetConv3dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflDepthwiseConv2d => {
+                let cause = ErrorCause::GetDepthwiseConv2dOptions;
+                OpOptions::DepthwiseConv2d(DepthwiseConv2dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetDepthwiseConv2dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetDepthwiseConv2dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetDepthwiseConv2dStrideWOption, cause)?,
+                    depth_multiplier: get_option(
+                        op,
+                        LiteRtGetDepthwiseConv2dDepthMultiplierOption,
+                        cause,
+                    )?,
+                    dilation_h: get_option(op, LiteRtGetDepthwiseConv2dDilationHOption, cause)?,
+                    dilation_w: get_option(op, LiteRtGetDepthwiseConv2dDilationWOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetDepthwiseConv2dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflTransposeConv => {
+                let cause = ErrorCause::GetTransposeConvOptions;
+                OpOptions::TransposeConv(TransposeConvOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetTransposeConvPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetTransposeConvStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetTransposeConvStrideWOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetTransposeConvFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflAveragePool2d => {
+                let cause = ErrorCause::GetAveragePool2dOptions;
+                OpOptions::AveragePool2d(Pool2dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetAveragePool2dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetAveragePool2dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetAveragePool2dStrideWOption, cause)?,
+                    filter_height: get_option(op, LiteRtGetAveragePool2dFilterHeightOption, cause)?,
+                    filter_width: get_option(op, LiteRtGetAveragePool2dFilterWidthOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetAveragePool2dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflMaxPool2d => {
+                let cause = ErrorCause::GetMaxPool2dOptions;
+                OpOptions::MaxPool2d(Pool2dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetMaxPool2dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetMaxPool2dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetMaxPool2dStrideWOption, cause)?,
+                    filter_height: get_option(op, LiteRtGetMaxPool2dFilterHeightOption, cause)?,
+                    filter_width: get_option(op, LiteRtGetMaxPool2dFilterWidthOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetMaxPool2dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflL2Pool2d => {
+                let cause = ErrorCause::GetL2Pool2dOptions;
+                OpOptions::L2Pool2d(Pool2dOptions {
+                    padding: Padding::from_c_value(get_option(
+                        op,
+                        LiteRtGetL2Pool2dPaddingOption,
+                        cause,
+                    )?)?,
+                    stride_h: get_option(op, LiteRtGetL2Pool2dStrideHOption, cause)?,
+                    stride_w: get_option(op, LiteRtGetL2Pool2dStrideWOption, cause)?,
+                    filter_height: get_option(op, LiteRtGetL2Pool2dFilterHeightOption, cause)?,
+                    filter_width: get_option(op, LiteRtGetL2Pool2dFilterWidthOption, cause)?,
+                    fused_activation: FusedActivation::from_c_value(get_option(
+                        op,
+                        LiteRtGetL2Pool2dFusedActivationOption,
+                        cause,
+                    )?)?,
+                })
+            }
+            OpCode::TflResizeBilinear => {
+                let cause = ErrorCause::GetResizeBilinearOptions;
+                OpOptions::ResizeBilinear(ResizeOptions {
+                    align_corners: get_option(
+                        op,
+                        LiteRtGetResizeBilinearAlignCornersOption,
+                        cause,
+                    )?,
+                    half_pixel_centers: get_option(
+                        op,
+                        LiteRtGetResizeBilinearHalfPixelCenterOption,
+                        cause,
+                    )?,
+                })
+            }
+            OpCode::TflResizeNearestNeighbor => {
+                let cause = ErrorCause::GetResizeNearestNeighborOptions;
+                OpOptions::ResizeNearestNeighbor(ResizeOptions {
+                    align_corners: get_option(
+                        op,
+                        LiteRtGetResizeNearestNeighborAlignCornersOption,
+                        cause,
+                    )?,
+                    half_pixel_centers: get_option(
+                        op,
+                        LiteRtGetResizeNearestNeighborHalfPixelCenterOption,
+                        cause,
+                    )?,
+                })
+            }
+            OpCode::TflLeakyRelu => OpOptions::LeakyRelu(LeakyReluOptions {
+                alpha: get_option(
+                    op,
+                    LiteRtGetLeakyReluAlphaOption,
+                    ErrorCause::GetLeakyReluOptions,
+                )?,
+            }),
+            OpCode::TflDepthToSpace => OpOptions::DepthToSpace(BlockSizeOptions {
+                block_size: get_option(
+                    op,
+                    LiteRtGetDepthToSpaceBlockSizeOption,
+                    ErrorCause::GetDepthToSpaceOptions,
+                )?,
+            }),
+            OpCode::TflSpaceToDepth => OpOptions::SpaceToDepth(BlockSizeOptions {
+                block_size: get_option(
+                    op,
+                    LiteRtGetSpaceToDepthBlockSizeOption,
+                    ErrorCause::GetSpaceToDepthOptions,
+                )?,
+            }),
+            OpCode::TflCumsum => {
+                let cause = ErrorCause::GetCumsumOptions;
+                OpOptions::Cumsum(CumsumOptions {
+                    exclusive: get_option(op, LiteRtGetCumsumExclusiveOption, cause)?,
+                    reverse: get_option(op, LiteRtGetCumsumReverseOption, cause)?,
+                })
+            }
+            OpCode::TflGelu => OpOptions::Gelu(GeluOptions {
+                approximate: get_option(
+                    op,
+                    LiteRtGetGeluApproximateOption,
+                    ErrorCause::GetGeluOptions,
+                )?,
+            }),
+            OpCode::TflMirrorPad => OpOptions::MirrorPad(MirrorPadOptions {
+                mode: MirrorPadMode::from_c_value(get_option(
+                    op,
+                    LiteRtGetMirrorPadModeOption,
+                    ErrorCause::GetMirrorPadOptions,
+                )?)?,
+            }),
+            OpCode::TflSqueeze => {
+                let mut squeeze_dims: *const i32 = std::ptr::null();
+                let mut num_squeeze_dims: i32 = 0;
+                call_check_status!(
+                    // SAFETY: op is always valid as it's taken from an Op wrapper.
+                    // We assume that the output is valid if the return status is OK or don't use the output pointers.
+                    unsafe {
+                        LiteRtGetSqueezeDimsOption(op, &mut squeeze_dims, &mut num_squeeze_dims)
+                    },
+                    ErrorCause::GetSqueezeOptions
+                );
+                OpOptions::Squeeze(SqueezeOptions {
+                    // SAFETY: The C API returns a pointer to `num_squeeze_dims` elements owned by
+                    // the model.
+                    squeeze_dims: unsafe { i32_array_to_vec(squeeze_dims, num_squeeze_dims) },
+                })
+            }
+            _ => OpOptions::None,
+        };
+        Ok(options)
+    }
+}
+
+// Copies a C array of i32 into a vector.
+// SAFETY: The function is unsafe because `data` must point to at least `size` elements.
+unsafe fn i32_array_to_vec(data: *const i32, size: i32) -> Vec<i32> {
+    if data.is_null() || size <= 0 {
+        return Vec::new();
+    }
+    // SAFETY: The caller guarantees that `data` points to `size` valid elements.
+    unsafe { std::slice::from_raw_parts(data, size as usize).to_vec() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_option_enum_values() {
+        assert_eq!(Padding::from_c_value(1), Ok(Padding::Valid));
+        assert_eq!(FusedActivation::from_c_value(3), Ok(FusedActivation::Relu6));
+        assert_eq!(MirrorPadMode::from_c_value(1), Ok(MirrorPadMode::Symmetric));
+        assert!(FusedActivation::from_c_value(6).is_err());
+        assert!(Padding::from_c_value(2).is_err());
+    }
+
+    #[test]
+    fn test_element_type_from_tflite_tensor_type() {
+        assert_eq!(
+            element_type_from_tflite_tensor_type(0),
+            Ok(ElementType::Float32)
+        );
+        assert_eq!(
+            element_type_from_tflite_tensor_type(9),
+            Ok(ElementType::Int8)
+        );
+        assert!(element_type_from_tflite_tensor_type(19).is_err());
+    }
+}
@@ -0,0 +1,339 @@
+//! Human-readable and serializable model summaries.
+//!
+//! A [`ModelSummary`] lists the signatures and subgraphs of a model with their tensors (name,
+//! element type, shape and quantization) and operations (code, operands and options). `Display`
+//! prints it as an indented outline, the same outline is printed by `Display` for [`Model`].
+//! With the `serde` feature the summary implements `serde::Serialize`, e.g. for JSON output.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::bindings::LiteRtTensor;
+use crate::error::{Error, ErrorCause};
+use crate::model::{Model, Op, Quantization, Subgraph, Tensor};
+use crate::op_code::OpCode;
+use crate::op_options::OpOptions;
+use crate::tensor_buffer::Shape;
+use crate::ElementType;
+
+/// A summary of the signatures and subgraphs of a model.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ModelSummary {
+    pub signatures: Vec<SignatureSummary>,
+    pub subgraphs: Vec<SubgraphSummary>,
+}
+
+/// A summary of a model signature.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SignatureSummary {
+    pub key: String,
+    /// Index of the subgraph the signature runs.
+    pub subgraph_index: Option<usize>,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+/// A summary of a subgraph, tensors are referenced by their index in `tensors`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SubgraphSummary {
+    pub index: usize,
+    pub is_main: bool,
+    pub inputs: Vec<usize>,
+    pub outputs: Vec<usize>,
+    pub tensors: Vec<TensorSummary>,
+    pub ops: Vec<OpSummary>,
+}
+
+/// A summary of a tensor.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TensorSummary {
+    pub name: String,
+    pub element_type: ElementType,
+    /// Dimensions of a ranked tensor, dynamic dimensions are `None`.
+    pub shape: Option<Vec<Option<usize>>>,
+    pub quantization: Quantization,
+    /// `true` if the tensor has constant weights.
+    pub is_constant: bool,
+}
+
+/// A summary of an operation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OpSummary {
+    pub code: OpCode,
+    pub inputs: Vec<usize>,
+    pub outputs: Vec<usize>,
+    /// The typed options, `None` if the operation has no options.
+    pub options: Option<OpOptions>,
+}
+
+// Assigns indices to the tensors of a subgraph in the order they are first seen.
+#[derive(Default)]
+struct TensorIndexer {
+    indices: HashMap<LiteRtTensor, usize>,
+    tensors: Vec<TensorSummary>,
+}
+
+impl TensorIndexer {
+    fn index(&mut self, tensor: &Tensor<'_>) -> Result<usize, Error> {
+        if let Some(&index) = self.indices.get(&tensor.raw_tensor) {
+            return Ok(index);
+        }
+        let summary = TensorSummary {
+            name: tensor.name()?.to_string(),
+            element_type: tensor.element_type()?,
+            shape: match tensor.tensor_type() {
+                Ok(tensor_type) => Some(tensor_type.shape.dims().to_vec()),
+                Err(e) if e.cause() == ErrorCause::UnrankedTensor => None,
+                Err(e) => return Err(e),
+            },
+            quantization: tensor.quantization()?,
+            is_constant: !tensor.weights()?.is_empty(),
+        };
+        let index = self.tensors.len();
+        self.indices.insert(tensor.raw_tensor, index);
+        self.tensors.push(summary);
+        Ok(index)
+    }
+}
+
+fn summarize_op(op: &Op<'_>, tensors: &mut TensorIndexer) -> Result<OpSummary, Error> {
+    let inputs = op
+        .inputs()?
+        .map(|t| tensors.index(&t?))
+        .collect::<Result<_, Error>>()?;
+    let outputs = op
+        .outputs()?
+        .map(|t| tensors.index(&t?))
+        .collect::<Result<_, Error>>()?;
+    let options = match op.options()? {
+        OpOptions::None => None,
+        options => Some(options),
+    };
+    Ok(OpSummary {
+        code: op.code()?,
+        inputs,
+        outputs,
+        options,
+    })
+}
+
+pub(crate) fn summarize_subgraph(
+    index: usize,
+    is_main: bool,
+    subgraph: &Subgraph<'_>,
+) -> Result<SubgraphSummary, Error> {
+    let mut tensors = TensorIndexer::default();
+    let inputs = (0..subgraph.num_inputs()?)
+        .map(|i| tensors.index(&subgraph.input_tensor(i)?))
+        .collect::<Result<_, Error>>()?;
+    let ops = subgraph
+        .ops()?
+        .map(|op| summarize_op(&op?, &mut tensors))
+        .collect::<Result<_, Error>>()?;
+    let outputs = (0..subgraph.num_outputs()?)
+        .map(|i| tensors.index(&subgraph.output_tensor(i)?))
+        .collect::<Result<_, Error>>()?;
+    Ok(SubgraphSummary {
+        index,
+        is_main,
+        inputs,
+        outputs,
+        tensors: tensors.tensors,
+        ops,
+    })
+}
+
+impl Model<'_> {
+    /// Returns a summary of the signatures, subgraphs, tensors and operations of the model.
+    pub fn summary(&self) -> Result<ModelSummary, Error> {
+        let main_subgraph_index = self.main_subgraph_index()?;
+        let mut raw_subgraphs = Vec::new();
+        let mut subgraphs = Vec::new();
+        for (i, subgraph) in self.subgraphs()?.enumerate() {
+            let subgraph = subgraph?;
+            subgraphs.push(summarize_subgraph(i, i == main_subgraph_index, &subgraph)?);
+            raw_subgraphs.push(subgraph.raw_subgraph);
+        }
+        let mut signatures = Vec::new();
+        for signature in self.signatures()? {
+            let signature = signature?;
+            let raw_subgraph = signature.subgraph()?.raw_subgraph;
+            let inputs = signature.input_names()?.map(|n| Ok(n?.to_string()));
+            let outputs = signature.output_names()?.map(|n| Ok(n?.to_string()));
+            signatures.push(SignatureSummary {
+                key: signature.key()?.to_string(),
+                subgraph_index: raw_subgraphs.iter().position(|&s| s == raw_subgraph),
+                inputs: inputs.collect::<Result<_, Error>>()?,
+                outputs: outputs.collect::<Result<_, Error>>()?,
+            });
+        }
+        Ok(ModelSummary {
+            signatures,
+            subgraphs,
+        })
+    }
+}
+
+impl fmt::Display for Model<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.summary() {
+            Ok(summary) => fmt::Display::fmt(&summary, f),
+            Err(e) => write!(f, "<failed to summarize model: {e}>"),
+        }
+    }
+}
+
+fn write_indices(f: &mut fmt::Formatter<'_>, indices: &[usize]) -> fmt::Result {
+    write!(f, "(")?;
+    for (i, index) in indices.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{index}")?;
+    }
+    write!(f, ")")
+}
+
+impl fmt::Display for ModelSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Model")?;
+        for signature in &self.signatures {
+            write!(f, "  Signature \"{}\"", signature.key)?;
+            if let Some(index) = signature.subgraph_index {
+                write!(f, " (subgraph {index})")?;
+            }
+            writeln!(f)?;
+            writeln!(f, "    inputs: {}", signature.inputs.join(", "))?;
+            writeln!(f, "    outputs: {}", signature.outputs.join(", "))?;
+        }
+        for subgraph in &self.subgraphs {
+            write!(f, "  Subgraph {}", subgraph.index)?;
+            if subgraph.is_main {
+                write!(f, " (main)")?;
+            }
+            writeln!(f)?;
+            write!(f, "    inputs: ")?;
+            write_indices(f, &subgraph.inputs)?;
+            write!(f, "\n    outputs: ")?;
+            write_indices(f, &subgraph.outputs)?;
+            writeln!(f, "\n    tensors:")?;
+            for (i, tensor) in subgraph.tensors.iter().enumerate() {
+                writeln!(f, "      {i}: {tensor}")?;
+            }
+            writeln!(f, "    ops:")?;
+            for (i, op) in subgraph.ops.iter().enumerate() {
+                writeln!(f, "      {i}: {op}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TensorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" {:?}", self.name, self.element_type)?;
+        match &self.shape {
+            Some(dims) => write!(f, "{}", Shape::new(dims.clone()))?,
+            None => write!(f, "[*]")?,
+        }
+        if self.is_constant {
+            write!(f, " const")?;
+        }
+        if self.quantization != Quantization::None {
+            write!(f, " {:?}", self.quantization)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for OpSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.code)?;
+        write_indices(f, &self.inputs)?;
+        write!(f, " -> ")?;
+        write_indices(f, &self.outputs)?;
+        if let Some(options) = &self.options {
+            write!(f, " {options:?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op_options::{ElementwiseOptions, FusedActivation};
+
+    #[test]
+    fn test_display_summary() {
+        let summary = ModelSummary {
+            signatures: vec![SignatureSummary {
+                key: "serving_default".to_string(),
+                subgraph_index: Some(0),
+                inputs: vec!["x".to_string()],
+                outputs: vec!["y".to_string()],
+            }],
+            subgraphs: vec![SubgraphSummary {
+                index: 0,
+                is_main: true,
+                inputs: vec![0],
+                outputs: vec![2],
+                tensors: vec![
+                    TensorSummary {
+                        name: "x".to_string(),
+                        element_type: ElementType::Float32,
+                        shape: Some(vec![None, Some(4)]),
+                        quantization: Quantization::None,
+                        is_constant: false,
+                    },
+                    TensorSummary {
+                        name: "w".to_string(),
+                        element_type: ElementType::Int8,
+                        shape: Some(vec![Some(4)]),
+                        quantization: Quantization::PerTensor {
+                            scale: 0.5,
+                            zero_point: 0,
+                        },
+                        is_constant: true,
+                    },
+                    TensorSummary {
+                        name: "y".to_string(),
+                        element_type: ElementType::Float32,
+                        shape: None,
+                        quantization: Quantization::None,
+                        is_constant: false,
+                    },
+                ],
+                ops: vec![OpSummary {
+                    code: OpCode::TflMul,
+                    inputs: vec![0, 1],
+                    outputs: vec![2],
+                    options: Some(OpOptions::Mul(ElementwiseOptions {
+                        fused_activation: FusedActivation::Relu,
+                    })),
+                }],
+            }],
+        };
+        assert_eq!(
+            summary.to_string(),
+            "Model\n\
+             \x20 Signature \"serving_default\" (subgraph 0)\n\
+             \x20   inputs: x\n\
+             \x20   outputs: y\n\
+             \x20 Subgraph 0 (main)\n\
+             \x20   inputs: (0)\n\
+             \x20   outputs: (2)\n\
+             \x20   tensors:\n\
+             \x20     0: \"x\" Float32[?, 4]\n\
+             \x20     1: \"w\" Int8[4] const PerTensor { scale: 0.5, zero_point: 0 }\n\
+             \x20     2: \"y\" Float32[*]\n\
+             \x20   ops:\n\
+             \x20     0: TflMul(0, 1) -> (2) Mul(ElementwiseOptions { fused_activation: Relu })\n"
+        );
+    }
+}
@@ -1,27 +1,70 @@
 #![allow(non_upper_case_globals)]
 
-use std::any::TypeId;
+use std::alloc::Layout;
+use std::any::Any;
 use std::ffi::c_void;
+use std::fmt;
 use std::marker::PhantomData;
 use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use crate::bindings::*;
 use crate::call_check_status;
 use crate::environment::Environment;
 use crate::error::{Error, ErrorCause};
+use crate::model::Quantization;
 
 /// Requirements for a tensor buffer.
 ///
 /// This struct represents the requirements for a tensor buffer. It is used to determine the
-/// supported buffer types and the buffer size.
+/// supported buffer types, the buffer size, strides and alignment.
+///
+/// Requirements returned by a compiled model are borrowed from it, joined requirements are owned.
 pub struct TensorBufferRequirements<'a> {
     raw_requirements: LiteRtTensorBufferRequirements,
+    owned: bool,
     _phantom: PhantomData<&'a LiteRtTensorBufferRequirements>,
 }
 
 impl<'a> TensorBufferRequirements<'a> {
     pub(crate) fn new(raw_requirements: LiteRtTensorBufferRequirements) -> Self {
-        Self { raw_requirements: raw_requirements, _phantom: PhantomData {} }
+        Self {
+            raw_requirements: raw_requirements,
+            owned: false,
+            _phantom: PhantomData {},
+        }
+    }
+
+    /// Joins the requirements from two sources, e.g. the output of one model and the input of
+    /// another, so that a single tensor buffer can be used for both.
+    ///
+    /// Returns an error if no tensor buffer can satisfy both requirements.
+    pub fn join(
+        a: &TensorBufferRequirements<'_>,
+        b: &TensorBufferRequirements<'_>,
+    ) -> Result<TensorBufferRequirements<'static>, Error> {
+        let mut raw_requirements: LiteRtTensorBufferRequirements = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: a.raw_requirements and b.raw_requirements are always valid, they're
+            // guaranteed to be initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe {
+                LiteRtJoinTensorBufferRequirements(
+                    a.raw_requirements,
+                    b.raw_requirements,
+                    &mut raw_requirements,
+                )
+            },
+            ErrorCause::JoinTensorBufferRequirements
+        );
+        Ok(TensorBufferRequirements {
+            raw_requirements,
+            owned: true,
+            _phantom: PhantomData {},
+        })
     }
 
     /// Returns the size of the buffer in bytes.
@@ -38,6 +81,45 @@ impl<'a> TensorBufferRequirements<'a> {
         Ok(buffer_size)
     }
 
+    /// Returns the alignment of the buffer in bytes.
+    pub fn alignment(&self) -> Result<usize, Error> {
+        let mut alignment: usize = 0;
+        call_check_status!(
+            // SAFETY: self.raw_requirements is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            unsafe {
+                LiteRtGetTensorBufferRequirementsAlignment(self.raw_requirements, &mut alignment)
+            },
+            ErrorCause::GetTensorBufferRequirementsAlignment
+        );
+        Ok(alignment)
+    }
+
+    /// Returns the strides of the buffer, empty if the buffer is dense.
+    pub fn strides(&self) -> Result<Vec<usize>, Error> {
+        let mut num_strides: i32 = 0;
+        let mut strides: *const u32 = std::ptr::null();
+        call_check_status!(
+            // SAFETY: self.raw_requirements is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe {
+                LiteRtGetTensorBufferRequirementsStrides(
+                    self.raw_requirements,
+                    &mut num_strides,
+                    &mut strides,
+                )
+            },
+            ErrorCause::GetTensorBufferRequirementsStrides
+        );
+        if num_strides <= 0 || strides.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: strides points to num_strides elements owned by the requirements.
+        let strides = unsafe { std::slice::from_raw_parts(strides, num_strides as usize) };
+        Ok(strides.iter().map(|&stride| stride as usize).collect())
+    }
+
     /// Returns the supported tensor buffer types.
     pub fn supported_types(&self) -> Result<Vec<TensorBufferType>, Error> {
         let mut num_supported_types: i32 = 0;
@@ -73,8 +155,21 @@ impl<'a> TensorBufferRequirements<'a> {
     }
 }
 
+impl Drop for TensorBufferRequirements<'_> {
+    fn drop(&mut self) {
+        if self.owned {
+            // SAFETY: self.raw_requirements is always valid, owned requirements are created by
+            // a wrapper function and destroyed only here.
+            unsafe {
+                LiteRtDestroyTensorBufferRequirements(self.raw_requirements);
+            }
+        }
+    }
+}
+
 /// The element type of a tensor buffer.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ElementType {
     None,
     Bool,
@@ -99,7 +194,6 @@ pub enum ElementType {
 }
 
 impl ElementType {
-    #[allow(dead_code)]
     pub(crate) fn to_c_enum(&self) -> LiteRtElementType {
         match self {
             Self::None => LiteRtElementType_kLiteRtElementTypeNone,
@@ -153,28 +247,364 @@ impl ElementType {
             )),
         }
     }
+}
+
+impl ElementType {
+    /// Returns the number of bits of one element, `None` for types without a fixed size.
+    pub fn bit_width(self) -> Option<usize> {
+        match self {
+            Self::Int4 => Some(4),
+            Self::Bool | Self::Int8 | Self::UInt8 => Some(8),
+            Self::Int16 | Self::UInt16 | Self::Float16 | Self::BFloat16 => Some(16),
+            Self::Int32 | Self::UInt32 | Self::Float32 => Some(32),
+            Self::Int64 | Self::UInt64 | Self::Float64 | Self::Complex64 => Some(64),
+            Self::Complex128 => Some(128),
+            Self::None | Self::TfResource | Self::TfString | Self::TfVariant => None,
+        }
+    }
+}
+
+/// The shape of a ranked tensor, dynamic dimensions are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    dims: Vec<Option<usize>>,
+    // Element strides stored in the layout, if any.
+    strides: Option<Vec<usize>>,
+}
+
+impl Shape {
+    /// Creates a dense shape from its dimensions.
+    pub fn new(dims: Vec<Option<usize>>) -> Self {
+        Self {
+            dims,
+            strides: None,
+        }
+    }
 
-    fn is_compatible<T: 'static>(self) -> bool {
-        let type_id = TypeId::of::<T>();
-        if TypeId::of::<bool>() == type_id {
-            self == Self::Bool
-        } else if TypeId::of::<i8>() == type_id || TypeId::of::<i8>() == type_id {
-            self == Self::Int8 || self == Self::UInt8
-        } else if TypeId::of::<i16>() == type_id || TypeId::of::<i16>() == type_id {
-            self == Self::Int16 || self == Self::UInt16
-        } else if TypeId::of::<i32>() == type_id || TypeId::of::<i32>() == type_id {
-            self == Self::Int32 || self == Self::UInt32
-        } else if TypeId::of::<f32>() == type_id {
-            self == Self::Float32
-        } else if TypeId::of::<f64>() == type_id {
-            self == Self::Float64
+    pub(crate) fn from_layout(layout: &LiteRtLayout) -> Self {
+        let rank = layout.rank() as usize;
+        let dims = layout.dimensions[..rank]
+            .iter()
+            .map(|&dim| if dim < 0 { None } else { Some(dim as usize) })
+            .collect();
+        let strides = if layout.has_strides() {
+            Some(
+                layout.strides[..rank]
+                    .iter()
+                    .map(|&stride| stride as usize)
+                    .collect(),
+            )
         } else {
-            // TODO: Add support for other types.
-            false
+            None
+        };
+        Self { dims, strides }
+    }
+
+    pub(crate) fn to_layout(&self) -> Result<LiteRtLayout, Error> {
+        let invalid = || {
+            Error::new(
+                ErrorCause::InvalidTensorType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )
+        };
+        let mut layout = LiteRtLayout::default();
+        if self.dims.len() > layout.dimensions.len() {
+            return Err(Error::new(
+                ErrorCause::InvalidTensorRank,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        layout.set_rank(self.dims.len() as u32);
+        for (dst, dim) in layout.dimensions.iter_mut().zip(&self.dims) {
+            *dst = match dim {
+                Some(dim) => i32::try_from(*dim).map_err(|_| invalid())?,
+                None => -1,
+            };
+        }
+        if let Some(strides) = &self.strides {
+            layout.set_has_strides(true);
+            for (dst, &stride) in layout.strides.iter_mut().zip(strides) {
+                *dst = u32::try_from(stride).map_err(|_| invalid())?;
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Returns the dimensions of the shape.
+    pub fn dims(&self) -> &[Option<usize>] {
+        &self.dims
+    }
+
+    /// Returns the number of dimensions.
+    pub fn rank(&self) -> usize {
+        self.dims.len()
+    }
+
+    /// Returns `true` if no dimension is dynamic.
+    pub fn is_static(&self) -> bool {
+        self.dims.iter().all(Option::is_some)
+    }
+
+    /// Returns the number of elements, `None` if the shape has dynamic dimensions.
+    pub fn num_elements(&self) -> Option<usize> {
+        self.dims
+            .iter()
+            .try_fold(1usize, |acc, &dim| acc.checked_mul(dim?))
+    }
+
+    /// Returns the stride of every dimension in elements, `None` if the shape has dynamic
+    /// dimensions. Shapes without explicit strides are dense and row-major.
+    pub fn strides(&self) -> Option<Vec<usize>> {
+        if let Some(strides) = &self.strides {
+            return Some(strides.clone());
+        }
+        let mut strides = vec![0; self.dims.len()];
+        let mut stride = 1usize;
+        for (dst, &dim) in strides.iter_mut().zip(&self.dims).rev() {
+            *dst = stride;
+            stride = stride.checked_mul(dim?)?;
+        }
+        Some(strides)
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, dim) in self.dims.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match dim {
+                Some(dim) => write!(f, "{dim}")?,
+                None => write!(f, "?")?,
+            }
         }
+        write!(f, "]")
     }
 }
 
+/// The type of a ranked tensor: its element type and shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorType {
+    pub element_type: ElementType,
+    pub shape: Shape,
+}
+
+impl TensorType {
+    pub(crate) fn from_c_struct(tensor_type: &LiteRtRankedTensorType) -> Result<Self, Error> {
+        Ok(Self {
+            element_type: ElementType::from_c_enum(tensor_type.element_type)?,
+            shape: Shape::from_layout(&tensor_type.layout),
+        })
+    }
+
+    pub(crate) fn to_c_struct(&self) -> Result<LiteRtRankedTensorType, Error> {
+        Ok(LiteRtRankedTensorType {
+            element_type: self.element_type.to_c_enum(),
+            layout: self.shape.to_layout()?,
+        })
+    }
+
+    /// Returns the size of the dense tensor data in bytes, `None` if the shape has dynamic
+    /// dimensions or the element type has no fixed size. Int4 elements are packed two per byte.
+    pub fn byte_size(&self) -> Option<usize> {
+        let bits = self
+            .shape
+            .num_elements()?
+            .checked_mul(self.element_type.bit_width()?)?;
+        Some(bits.div_ceil(8))
+    }
+}
+
+impl fmt::Display for TensorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}{}", self.element_type, self.shape)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A Rust type that can be stored in a tensor buffer.
+///
+/// The trait is sealed, it's implemented for the scalar types that have the same layout as an
+/// [`ElementType`]: the integer types, `f32` and `f64`, [`PackedInt4`], `half::f16` and
+/// `half::bf16` with the `half` feature and `num_complex::Complex` with the `num-complex` feature.
+///
+/// `bool` isn't a tensor element because a tensor buffer can hold any byte, bool tensor buffers
+/// are accessed as `u8` instead.
+pub trait TensorElement: sealed::Sealed + Copy + Send + Sync + 'static {
+    /// The element type stored as `Self`.
+    const ELEMENT_TYPE: ElementType;
+}
+
+macro_rules! impl_tensor_element {
+    ($($t:ty => $element_type:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl TensorElement for $t {
+                const ELEMENT_TYPE: ElementType = ElementType::$element_type;
+            }
+        )*
+    };
+}
+
+impl_tensor_element!(
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    PackedInt4 => Int4,
+);
+
+#[cfg(feature = "half")]
+impl_tensor_element!(half::f16 => Float16, half::bf16 => BFloat16);
+
+#[cfg(feature = "num-complex")]
+impl_tensor_element!(
+    num_complex::Complex<f32> => Complex64,
+    num_complex::Complex<f64> => Complex128,
+);
+
+// Returns whether tensor buffers of `element_type` can be accessed as `T`, bool tensor buffers are
+// accessed as u8.
+fn is_accessible_as<T: TensorElement>(element_type: ElementType) -> bool {
+    T::ELEMENT_TYPE == element_type
+        || (T::ELEMENT_TYPE == ElementType::UInt8 && element_type == ElementType::Bool)
+}
+
+/// Two signed 4-bit integers packed in one byte, the Int4 storage format.
+///
+/// The first element is stored in the low nibble.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct PackedInt4(u8);
+
+impl PackedInt4 {
+    /// Packs two values, only their 4 low bits are kept.
+    pub fn new(low: i8, high: i8) -> Self {
+        Self((low as u8 & 0x0f) | ((high as u8) << 4))
+    }
+
+    /// Wraps a packed byte.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the packed byte.
+    pub fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the first element.
+    pub fn low(self) -> i8 {
+        ((self.0 << 4) as i8) >> 4
+    }
+
+    /// Returns the second element.
+    pub fn high(self) -> i8 {
+        (self.0 as i8) >> 4
+    }
+}
+
+// Integer types that can hold quantized values.
+trait QuantizedStorage: Copy {
+    fn from_i64_saturating(value: i64) -> Self;
+    fn to_i64(self) -> i64;
+}
+
+macro_rules! impl_quantized_storage {
+    ($($t:ty),*) => {
+        $(
+            impl QuantizedStorage for $t {
+                fn from_i64_saturating(value: i64) -> Self {
+                    value.clamp(<$t>::MIN as i64, <$t>::MAX as i64) as $t
+                }
+                fn to_i64(self) -> i64 {
+                    self as i64
+                }
+            }
+        )*
+    };
+}
+
+impl_quantized_storage!(i8, u8, i16, i32);
+
+// Scale and zero point lookup for every element of a quantized tensor.
+struct QuantizationParams<'q> {
+    scales: &'q [f32],
+    zero_points: &'q [i64],
+    // Number of consecutive elements that share the same channel.
+    inner_size: usize,
+}
+
+impl<'q> QuantizationParams<'q> {
+    fn new(quantization: &'q Quantization, dims: &[i32]) -> Result<Self, Error> {
+        let invalid = || {
+            Error::new(
+                ErrorCause::InvalidQuantizationParameters,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )
+        };
+        match quantization {
+            Quantization::None => Err(invalid()),
+            Quantization::PerTensor { scale, zero_point } => Ok(Self {
+                scales: std::slice::from_ref(scale),
+                zero_points: std::slice::from_ref(zero_point),
+                inner_size: 1,
+            }),
+            Quantization::PerChannel {
+                scales,
+                zero_points,
+                quantized_dimension,
+            } => {
+                let dim = usize::try_from(*quantized_dimension).map_err(|_| invalid())?;
+                if dim >= dims.len()
+                    || scales.is_empty()
+                    || scales.len() != zero_points.len()
+                    || usize::try_from(dims[dim]).ok() != Some(scales.len())
+                {
+                    return Err(invalid());
+                }
+                let mut inner_size: usize = 1;
+                for &d in &dims[dim + 1..] {
+                    inner_size *= usize::try_from(d).map_err(|_| invalid())?;
+                }
+                Ok(Self {
+                    scales,
+                    zero_points,
+                    inner_size: inner_size.max(1),
+                })
+            }
+        }
+    }
+
+    fn get(&self, index: usize) -> (f32, i64) {
+        let channel = (index / self.inner_size) % self.scales.len();
+        (self.scales[channel], self.zero_points[channel])
+    }
+
+    fn quantize<Q: QuantizedStorage>(&self, index: usize, value: f32) -> Q {
+        let (scale, zero_point) = self.get(index);
+        let q = (value / scale).round() as i64;
+        Q::from_i64_saturating(q.saturating_add(zero_point))
+    }
+
+    fn dequantize<Q: QuantizedStorage>(&self, index: usize, value: Q) -> f32 {
+        let (scale, zero_point) = self.get(index);
+        (value.to_i64() - zero_point) as f32 * scale
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TensorBufferType {
     Unknown,
     HostMemory,
@@ -247,24 +677,325 @@ impl TensorBufferType {
 pub struct TensorBuffer<'a> {
     pub(crate) raw_tensor_buffer: LiteRtTensorBuffer,
     element_type: ElementType,
+    shared: Arc<SharedState>,
     _phantom: PhantomData<&'a LiteRtTensorBuffer>,
 }
 
-struct TensorBufferLock<'a, T> {
-    buffer: &'a TensorBuffer<'a>,
+// SAFETY: The native tensor buffer is reference counted atomically. Its lock state isn't
+// thread-safe, so every call that locks the buffer, clears it or runs a model on it first
+// acquires SharedState::in_use, which is shared by all the handles of the buffer.
+unsafe impl Send for TensorBuffer<'_> {}
+// SAFETY: See Send, the methods taking &self only read immutable properties of the native
+// tensor buffer or go through SharedState::in_use.
+unsafe impl Sync for TensorBuffer<'_> {}
+
+// State shared by all the handles of a tensor buffer.
+#[derive(Default)]
+struct SharedState {
+    // `true` while the tensor buffer is locked or used by a model run through any handle,
+    // LiteRT allows one lock at a time.
+    in_use: AtomicBool,
+    // Owner of the host memory wrapped by from_owned_host_memory, dropped after the last handle
+    // of the tensor buffer is destroyed.
+    _host_memory: Option<Box<dyn Any + Send + Sync>>,
+}
+
+impl SharedState {
+    fn acquire(&self) -> Result<InUse<'_>, Error> {
+        if self
+            .in_use
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(Error::new(
+                ErrorCause::TensorBufferAlreadyLocked,
+                LiteRtStatus_kLiteRtStatusErrorRuntimeFailure,
+            ));
+        }
+        Ok(InUse { shared: self })
+    }
+}
+
+// Marks a tensor buffer as used until it's dropped.
+pub(crate) struct InUse<'b> {
+    shared: &'b SharedState,
+}
+
+impl Drop for InUse<'_> {
+    fn drop(&mut self) {
+        self.shared.in_use.store(false, Ordering::Release);
+    }
+}
+
+// Marks the tensor buffers as used by a model run, a buffer passed several times is only
+// marked once.
+pub(crate) fn acquire_all<'b>(
+    buffers: impl IntoIterator<Item = &'b TensorBuffer<'b>>,
+) -> Result<Vec<InUse<'b>>, Error> {
+    let mut acquired: Vec<InUse<'b>> = Vec::new();
+    for buffer in buffers {
+        let shared: &'b SharedState = &buffer.shared;
+        if !acquired
+            .iter()
+            .any(|in_use| std::ptr::eq(in_use.shared, shared))
+        {
+            acquired.push(shared.acquire()?);
+        }
+    }
+    Ok(acquired)
+}
+
+struct TensorBufferLock<'b, T> {
+    raw_tensor_buffer: LiteRtTensorBuffer,
     raw_data: *mut T,
+    // Released after the native tensor buffer is unlocked.
+    _in_use: InUse<'b>,
 }
 
 impl<T> Drop for TensorBufferLock<'_, T> {
     fn drop(&mut self) {
-        // SAFETY: self.buffer.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
-        // a wrapper function.
+        // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+        // a wrapper function and outlives the lock.
         unsafe {
-            LiteRtUnlockTensorBuffer(self.buffer.raw_tensor_buffer);
+            LiteRtUnlockTensorBuffer(self.raw_tensor_buffer);
         }
     }
 }
 
+/// Heap memory that [`TensorBuffer::from_owned_host_memory`] can take ownership of.
+///
+/// The trait is sealed, it's implemented for [`AlignedHostMemory`], `Box<[T]>` and `Vec<T>`: they
+/// own their elements and keep them at the same address when the container is moved.
+pub trait OwnedHostMemory<T: TensorElement>: sealed::Sealed + Send + Sync + 'static {
+    #[doc(hidden)]
+    fn host_memory(&mut self) -> &mut [T];
+}
+
+impl<T: TensorElement> sealed::Sealed for Box<[T]> {}
+
+impl<T: TensorElement> OwnedHostMemory<T> for Box<[T]> {
+    fn host_memory(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: TensorElement> sealed::Sealed for Vec<T> {}
+
+impl<T: TensorElement> OwnedHostMemory<T> for Vec<T> {
+    fn host_memory(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// Zero-initialized heap memory with a given alignment, to be used as the host memory of a
+/// [`TensorBuffer`].
+///
+/// `Box<[T]>` and `Vec<T>` are only aligned for `T`, while LiteRT usually requires host memory
+/// aligned to 64 bytes, see [`TensorBufferRequirements::alignment`].
+pub struct AlignedHostMemory<T: TensorElement> {
+    data: NonNull<T>,
+    len: usize,
+    layout: Layout,
+}
+
+// SAFETY: AlignedHostMemory owns its elements like a Box<[T]>, and T is Send and Sync.
+unsafe impl<T: TensorElement> Send for AlignedHostMemory<T> {}
+// SAFETY: See Send.
+unsafe impl<T: TensorElement> Sync for AlignedHostMemory<T> {}
+
+impl<T: TensorElement> AlignedHostMemory<T> {
+    /// Allocates `len` elements set to zero, aligned to `alignment` bytes or to the alignment of
+    /// `T` if it's bigger.
+    ///
+    /// `alignment` must be a power of two.
+    pub fn new(len: usize, alignment: usize) -> Result<Self, Error> {
+        let layout = mem::size_of::<T>()
+            .checked_mul(len)
+            .and_then(|size| {
+                Layout::from_size_align(size.max(1), alignment.max(mem::align_of::<T>())).ok()
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorCause::InvalidHostMemoryLayout,
+                    LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+                )
+            })?;
+        // SAFETY: layout has a non-zero size.
+        let data = unsafe { std::alloc::alloc_zeroed(layout) } as *mut T;
+        let Some(data) = NonNull::new(data) else {
+            std::alloc::handle_alloc_error(layout);
+        };
+        Ok(Self { data, len, layout })
+    }
+
+    /// Allocates host memory with the buffer size and alignment of `requirements`, e.g. from
+    /// `CompiledModel::input_buffer_requirements`.
+    pub fn from_requirements(requirements: &TensorBufferRequirements<'_>) -> Result<Self, Error> {
+        let len = requirements.buffer_size()?.div_ceil(mem::size_of::<T>());
+        Self::new(len, requirements.alignment()?)
+    }
+}
+
+impl<T: TensorElement> Deref for AlignedHostMemory<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: self.data points to self.len initialized elements, every tensor element type is
+        // valid when zeroed.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl<T: TensorElement> DerefMut for AlignedHostMemory<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: See deref, the memory is borrowed mutably through self.
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl<T: TensorElement> Drop for AlignedHostMemory<T> {
+    fn drop(&mut self) {
+        // SAFETY: self.data was allocated with self.layout in new.
+        unsafe { std::alloc::dealloc(self.data.as_ptr() as *mut u8, self.layout) };
+    }
+}
+
+impl<T: TensorElement> fmt::Debug for AlignedHostMemory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlignedHostMemory")
+            .field("len", &self.len)
+            .field("alignment", &self.layout.align())
+            .finish()
+    }
+}
+
+impl<T: TensorElement> sealed::Sealed for AlignedHostMemory<T> {}
+
+impl<T: TensorElement> OwnedHostMemory<T> for AlignedHostMemory<T> {
+    fn host_memory(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+fn create_tensor_buffer_from_host_memory<T: TensorElement>(
+    tensor_type: &TensorType,
+    requirements: &TensorBufferRequirements<'_>,
+    data: &mut [T],
+) -> Result<LiteRtTensorBuffer, Error> {
+    if !is_accessible_as::<T>(tensor_type.element_type) {
+        return Err(Error::new(
+            ErrorCause::IncompatibleHostMemoryType,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        ));
+    }
+    let Some(byte_size) = tensor_type.byte_size() else {
+        return Err(Error::new(
+            ErrorCause::InvalidTensorType,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        ));
+    };
+    if !requirements
+        .supported_types()?
+        .contains(&TensorBufferType::HostMemory)
+    {
+        return Err(Error::new(
+            ErrorCause::UnsupportedTensorBufferType,
+            LiteRtStatus_kLiteRtStatusErrorUnsupported,
+        ));
+    }
+    // The requirements may ask for padding after the tensor data.
+    let host_buffer_size = mem::size_of_val(data);
+    if host_buffer_size < byte_size.max(requirements.buffer_size()?) {
+        return Err(Error::new(
+            ErrorCause::HostMemoryTooSmall,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        ));
+    }
+    let alignment = requirements.alignment()?.max(1);
+    if !(data.as_ptr() as usize).is_multiple_of(alignment) {
+        return Err(Error::new(
+            ErrorCause::MisalignedHostMemory,
+            LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+        ));
+    }
+    let raw_tensor_type = tensor_type.to_c_struct()?;
+    let mut buffer_ptr: LiteRtTensorBuffer = std::ptr::null_mut();
+    call_check_status!(
+        // SAFETY: raw_tensor_type is a valid tensor type created above.
+        // data is valid for host_buffer_size bytes and outlives the tensor buffer, it's
+        // guaranteed by the callers. The null deallocator leaves the data owned by the caller.
+        unsafe {
+            LiteRtCreateTensorBufferFromHostMemory(
+                &raw_tensor_type,
+                data.as_mut_ptr() as *mut c_void,
+                host_buffer_size,
+                None,
+                &mut buffer_ptr,
+            )
+        },
+        ErrorCause::CreateTensorBufferFromHostMemory
+    );
+    Ok(buffer_ptr)
+}
+
+// Checks that the locked memory can be viewed as a slice of T.
+fn check_locked_data<T>(lock: &TensorBufferLock<'_, T>) -> Result<(), Error> {
+    if lock.raw_data.is_null() || !lock.raw_data.is_aligned() {
+        return Err(Error::new(
+            ErrorCause::MisalignedTensorBufferData,
+            LiteRtStatus_kLiteRtStatusErrorRuntimeFailure,
+        ));
+    }
+    Ok(())
+}
+
+/// A read lock on the host memory of a [`TensorBuffer`], returned by [`TensorBuffer::map_read`].
+///
+/// Dereferences to the elements of the tensor buffer without copying them, the tensor buffer is
+/// unlocked when the guard is dropped.
+pub struct ReadGuard<'b, T> {
+    lock: TensorBufferLock<'b, T>,
+    len: usize,
+}
+
+impl<T> Deref for ReadGuard<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: lock.raw_data is non-null and aligned for T, it's checked by map_read.
+        // len elements fit in the locked memory, which stays valid until the lock is dropped.
+        unsafe { std::slice::from_raw_parts(self.lock.raw_data, self.len) }
+    }
+}
+
+/// A read-write lock on the host memory of a [`TensorBuffer`], returned by
+/// [`TensorBuffer::map_write`].
+///
+/// Dereferences to the elements of the tensor buffer without copying them, the tensor buffer is
+/// unlocked when the guard is dropped.
+pub struct WriteGuard<'b, T> {
+    lock: TensorBufferLock<'b, T>,
+    len: usize,
+}
+
+impl<T> Deref for WriteGuard<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: lock.raw_data is non-null and aligned for T, it's checked by map_write.
+        // len elements fit in the locked memory, which stays valid until the lock is dropped.
+        unsafe { std::slice::from_raw_parts(self.lock.raw_data, self.len) }
+    }
+}
+
+impl<T> DerefMut for WriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: see deref(), the guard is created from a mutable borrow of the tensor buffer so
+        // no other reference to the locked memory exists.
+        unsafe { std::slice::from_raw_parts_mut(self.lock.raw_data, self.len) }
+    }
+}
+
 impl<'a> TensorBuffer<'a> {
     pub(crate) fn new(
         environment: &Environment,
@@ -288,7 +1019,105 @@ impl<'a> TensorBuffer<'a> {
             },
             ErrorCause::CreateManagedTensorBuffer
         );
-        Ok(TensorBuffer { raw_tensor_buffer: buffer_ptr, element_type, _phantom: PhantomData {} })
+        Ok(TensorBuffer {
+            raw_tensor_buffer: buffer_ptr,
+            element_type,
+            shared: Arc::default(),
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Creates a managed tensor buffer that satisfies `requirements`, LiteRT picks the buffer
+    /// type and uses the size, strides and alignment of the requirements.
+    pub fn from_requirements(
+        environment: &Environment,
+        tensor_type: &TensorType,
+        requirements: &TensorBufferRequirements<'_>,
+    ) -> Result<TensorBuffer<'a>, Error> {
+        let raw_tensor_type = tensor_type.to_c_struct()?;
+        let mut buffer_ptr: LiteRtTensorBuffer = std::ptr::null_mut();
+        call_check_status!(
+            // SAFETY: environment.raw_environment and requirements.raw_requirements are always
+            // valid, they're guaranteed to be initialized by a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe {
+                LiteRtCreateManagedTensorBufferFromRequirements(
+                    environment.raw_environment,
+                    &raw_tensor_type,
+                    requirements.raw_requirements,
+                    &mut buffer_ptr,
+                )
+            },
+            ErrorCause::CreateManagedTensorBufferFromRequirements
+        );
+        Ok(TensorBuffer {
+            raw_tensor_buffer: buffer_ptr,
+            element_type: tensor_type.element_type,
+            shared: Arc::default(),
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Creates a tensor buffer that uses `data` as its host memory, without copying it.
+    ///
+    /// `T` must match the element type of `tensor_type` exactly and the shape must be static.
+    /// `requirements`, e.g. from `CompiledModel::input_buffer_requirements`, must support
+    /// [`TensorBufferType::HostMemory`]. `data` must be at least as big as the tensor and the
+    /// requirements' buffer size, and aligned to [`TensorBufferRequirements::alignment`] bytes,
+    /// which plain `Vec` allocations usually aren't.
+    pub fn from_host_memory<T: TensorElement>(
+        tensor_type: &TensorType,
+        requirements: &TensorBufferRequirements<'_>,
+        data: &'a mut [T],
+    ) -> Result<TensorBuffer<'a>, Error> {
+        let raw_tensor_buffer =
+            create_tensor_buffer_from_host_memory(tensor_type, requirements, data)?;
+        Ok(TensorBuffer {
+            raw_tensor_buffer,
+            element_type: tensor_type.element_type,
+            shared: Arc::default(),
+            _phantom: PhantomData {},
+        })
+    }
+
+    /// Creates a tensor buffer that takes ownership of its host memory.
+    ///
+    /// `data` is dropped after the tensor buffer is destroyed. The requirements on the data are
+    /// the same as for [`TensorBuffer::from_host_memory`], [`AlignedHostMemory::from_requirements`]
+    /// allocates memory that satisfies them.
+    ///
+    /// ```rust,no_run
+    /// # use litert::{AlignedHostMemory, TensorBuffer, TensorBufferRequirements, TensorType};
+    /// # fn example(
+    /// #     tensor_type: &TensorType,
+    /// #     requirements: &TensorBufferRequirements,
+    /// # ) -> Result<(), litert::Error> {
+    /// let frame = AlignedHostMemory::<f32>::from_requirements(requirements)?;
+    /// let buffer = TensorBuffer::from_owned_host_memory(tensor_type, requirements, frame)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_owned_host_memory<T, D>(
+        tensor_type: &TensorType,
+        requirements: &TensorBufferRequirements<'_>,
+        data: D,
+    ) -> Result<TensorBuffer<'static>, Error>
+    where
+        T: TensorElement,
+        D: OwnedHostMemory<T>,
+    {
+        let mut data = Box::new(data);
+        let raw_tensor_buffer =
+            create_tensor_buffer_from_host_memory(tensor_type, requirements, data.host_memory())?;
+        Ok(TensorBuffer {
+            raw_tensor_buffer,
+            element_type: tensor_type.element_type,
+            shared: Arc::new(SharedState {
+                in_use: AtomicBool::new(false),
+                _host_memory: Some(data),
+            }),
+            _phantom: PhantomData {},
+        })
     }
 
     /// Returns the element type of the tensor buffer.
@@ -296,40 +1125,194 @@ impl<'a> TensorBuffer<'a> {
         self.element_type
     }
 
-    fn lock_read<T>(&'a self) -> Result<TensorBufferLock<'a, T>, Error> {
+    fn lock<T>(
+        &self,
+        lock_mode: LiteRtTensorBufferLockMode,
+        cause: ErrorCause,
+    ) -> Result<TensorBufferLock<'_, T>, Error> {
+        // All the handles of the tensor buffer share its lock.
+        let in_use = self.shared.acquire()?;
         let mut data: *mut c_void = std::ptr::null_mut();
         call_check_status!(
             // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
             // a wrapper function.
             // We assume that the output is valid if the return status is OK or don't use the output pointer.
-            unsafe {
-                LiteRtLockTensorBuffer(
-                    self.raw_tensor_buffer,
-                    &mut data,
-                    LiteRtTensorBufferLockMode_kLiteRtTensorBufferLockModeRead,
-                )
-            },
-            ErrorCause::LockTensorBufferRead
+            unsafe { LiteRtLockTensorBuffer(self.raw_tensor_buffer, &mut data, lock_mode) },
+            cause
         );
-        Ok(TensorBufferLock { buffer: self, raw_data: data as *mut T })
+        Ok(TensorBufferLock {
+            raw_tensor_buffer: self.raw_tensor_buffer,
+            raw_data: data as *mut T,
+            _in_use: in_use,
+        })
     }
 
-    fn lock_write<T>(&'a self) -> Result<TensorBufferLock<'a, T>, Error> {
-        let mut data: *mut c_void = std::ptr::null_mut();
+    fn lock_read<T>(&self) -> Result<TensorBufferLock<'_, T>, Error> {
+        self.lock(
+            LiteRtTensorBufferLockMode_kLiteRtTensorBufferLockModeRead,
+            ErrorCause::LockTensorBufferRead,
+        )
+    }
+
+    fn lock_write<T>(&mut self) -> Result<TensorBufferLock<'_, T>, Error> {
+        self.lock(
+            LiteRtTensorBufferLockMode_kLiteRtTensorBufferLockModeWrite,
+            ErrorCause::LockTensorBufferWrite,
+        )
+    }
+
+    fn lock_read_write<T>(&mut self) -> Result<TensorBufferLock<'_, T>, Error> {
+        self.lock(
+            LiteRtTensorBufferLockMode_kLiteRtTensorBufferLockModeReadWrite,
+            ErrorCause::LockTensorBufferReadWrite,
+        )
+    }
+
+    /// Locks the tensor buffer for reading and returns a guard that dereferences to its elements
+    /// without copying them.
+    ///
+    /// `T` must match the element type of the tensor buffer exactly. LiteRT allows one lock at a
+    /// time, even for reading: while the guard is alive, any other `map_read` or `read` on the
+    /// tensor buffer, through this handle or a clone, fails with
+    /// `ErrorCause::TensorBufferAlreadyLocked`.
+    ///
+    /// ```rust,no_run
+    /// # fn example(buffer: &litert::TensorBuffer) -> Result<(), litert::Error> {
+    /// let data = buffer.map_read::<f32>()?;
+    /// let max = data.iter().copied().fold(f32::MIN, f32::max);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_read<T: TensorElement>(&self) -> Result<ReadGuard<'_, T>, Error> {
+        if !is_accessible_as::<T>(self.element_type) {
+            return Err(Error::new(
+                ErrorCause::IncompatibleReadType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        let packed_size = self.packed_size()?;
+        let lock = self.lock_read()?;
+        check_locked_data(&lock)?;
+        Ok(ReadGuard {
+            lock,
+            len: packed_size / mem::size_of::<T>(),
+        })
+    }
+
+    /// Locks the tensor buffer for reading and writing and returns a guard that dereferences to
+    /// its elements without copying them.
+    ///
+    /// `T` must match the element type of the tensor buffer exactly. The guard mutably borrows
+    /// the tensor buffer, so no other guard can exist at the same time. Locking fails if another
+    /// handle of the tensor buffer is mapped.
+    ///
+    /// ```rust,no_run
+    /// # fn example(buffer: &mut litert::TensorBuffer, frame: &[u8]) -> Result<(), litert::Error> {
+    /// let mut data = buffer.map_write::<f32>()?;
+    /// for (dst, &src) in data.iter_mut().zip(frame) {
+    ///     *dst = src as f32 / 255.0;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_write<T: TensorElement>(&mut self) -> Result<WriteGuard<'_, T>, Error> {
+        if !is_accessible_as::<T>(self.element_type) {
+            return Err(Error::new(
+                ErrorCause::IncompatibleWriteType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        let packed_size = self.packed_size()?;
+        let lock = self.lock_read_write()?;
+        check_locked_data(&lock)?;
+        Ok(WriteGuard {
+            lock,
+            len: packed_size / mem::size_of::<T>(),
+        })
+    }
+
+    /// Returns the type of the tensor stored in the tensor buffer.
+    pub fn tensor_type(&self) -> Result<TensorType, Error> {
+        TensorType::from_c_struct(&self.raw_tensor_type()?)
+    }
+
+    /// Returns the type of the memory backing the tensor buffer.
+    pub fn buffer_type(&self) -> Result<TensorBufferType, Error> {
+        let mut buffer_type = LiteRtTensorBufferType_kLiteRtTensorBufferTypeUnknown;
         call_check_status!(
             // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
             // a wrapper function.
-            // We assume that the output is valid if the return status is OK or don't use the output pointer.
-            unsafe {
-                LiteRtLockTensorBuffer(
-                    self.raw_tensor_buffer,
-                    &mut data,
-                    LiteRtTensorBufferLockMode_kLiteRtTensorBufferLockModeWrite,
-                )
-            },
-            ErrorCause::LockTensorBufferWrite
+            unsafe { LiteRtGetTensorBufferType(self.raw_tensor_buffer, &mut buffer_type) },
+            ErrorCause::GetTensorBufferType
         );
-        Ok(TensorBufferLock { buffer: self, raw_data: data as *mut T })
+        TensorBufferType::from_c_enum(buffer_type)
+    }
+
+    /// Returns the size of the underlying buffer in bytes, it can be bigger than
+    /// [`TensorBuffer::packed_size`] if the buffer has strides or padding.
+    pub fn size(&self) -> Result<usize, Error> {
+        let mut size: usize = 0;
+        call_check_status!(
+            // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            unsafe { LiteRtGetTensorBufferSize(self.raw_tensor_buffer, &mut size) },
+            ErrorCause::GetTensorBufferSize
+        );
+        Ok(size)
+    }
+
+    /// Returns the offset of the tensor data in the underlying buffer in bytes.
+    pub fn offset(&self) -> Result<usize, Error> {
+        let mut offset: usize = 0;
+        call_check_status!(
+            // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            unsafe { LiteRtGetTensorBufferOffset(self.raw_tensor_buffer, &mut offset) },
+            ErrorCause::GetTensorBufferOffset
+        );
+        Ok(offset)
+    }
+
+    /// Zeroes the tensor buffer, possibly asynchronously for buffer types that support it.
+    ///
+    /// Buffer types without an efficient clear operation are zeroed through a write lock.
+    pub fn clear(&mut self) -> Result<(), Error> {
+        let status = {
+            let _in_use = self.shared.acquire()?;
+            // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            unsafe { LiteRtClearTensorBuffer(self.raw_tensor_buffer) }
+        };
+        if status == LiteRtStatus_kLiteRtStatusErrorUnsupported {
+            let size = self.packed_size()?;
+            let lock = self.lock_write::<u8>()?;
+            check_locked_data(&lock)?;
+            // SAFETY: lock.raw_data is non-null, it's checked above.
+            // size is the packed size of the tensor buffer.
+            unsafe { std::ptr::write_bytes(lock.raw_data, 0, size) };
+        } else if status != LiteRtStatus_kLiteRtStatusOk {
+            return Err(Error::new(ErrorCause::ClearTensorBuffer, status));
+        }
+        Ok(())
+    }
+
+    /// Sets every element of the tensor buffer to `value`.
+    ///
+    /// `T` must match the element type of the tensor buffer exactly.
+    pub fn fill<T: TensorElement>(&mut self, value: T) -> Result<(), Error> {
+        if !is_accessible_as::<T>(self.element_type) {
+            return Err(Error::new(
+                ErrorCause::IncompatibleWriteType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            ));
+        }
+        let len = self.packed_size()? / mem::size_of::<T>();
+        let lock = self.lock_write::<T>()?;
+        check_locked_data(&lock)?;
+        // SAFETY: lock.raw_data is non-null and aligned for T, it's checked above.
+        // len elements fit in the locked memory.
+        unsafe { std::slice::from_raw_parts_mut(lock.raw_data, len) }.fill(value);
+        Ok(())
     }
 
     /// Returns the size of the tensor buffer in bytes.
@@ -346,19 +1329,20 @@ impl<'a> TensorBuffer<'a> {
 
     /// Writes data to the tensor buffer.
     ///
-    /// The data must be compatible with the element type of the tensor buffer.
+    /// `T` must match the element type of the tensor buffer exactly.
     /// The data must be big enough to fill the tensor buffer.
     ///
     /// Returns the number of bytes written to the tensor buffer.
-    pub fn write<T: 'static>(&self, data: &[T]) -> Result<usize, Error> {
-        if !self.element_type.is_compatible::<T>() {
+    pub fn write<T: TensorElement>(&mut self, data: &[T]) -> Result<usize, Error> {
+        if !is_accessible_as::<T>(self.element_type) {
             return Err(Error::new(
                 ErrorCause::IncompatibleWriteType,
                 LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
             ));
         }
-        let lock = self.lock_write()?;
         let dst_size = self.packed_size()?;
+        let lock = self.lock_write()?;
+        check_locked_data(&lock)?;
         let src_size = mem::size_of_val(data);
         if dst_size < src_size {
             return Err(Error::new(
@@ -367,14 +1351,17 @@ impl<'a> TensorBuffer<'a> {
             ));
         }
         // TODO(mgubin): Do something when input data is smaller that the tensor buffer.
-        // SAFETY: lock.raw_data is always valid, it's guaranteed to be initialized by
-        // lock_write function.
+        // SAFETY: lock.raw_data is non-null and aligned for T, it's checked above.
         // data is a pointer to the start of the data buffer, it's valid as provided by safe
         // Rust code.
         // src_size / std::mem::size_of::<T>() is the number of elements to copy, it's
         // guaranteed that it won't overwrite the output buffer of read after the end of the input data.
         unsafe {
-            std::ptr::copy(data.as_ptr(), lock.raw_data, src_size / std::mem::size_of::<T>());
+            std::ptr::copy(
+                data.as_ptr(),
+                lock.raw_data,
+                src_size / std::mem::size_of::<T>(),
+            );
         }
 
         Ok(src_size)
@@ -382,18 +1369,23 @@ impl<'a> TensorBuffer<'a> {
 
     /// Reads data from the tensor buffer.
     ///
-    /// The data must be compatible with the element type of the tensor buffer.
+    /// `T` must match the element type of the tensor buffer exactly.
     /// The data must be big enough.
     ///
+    /// The tensor buffer is locked for the duration of the copy, so this fails with
+    /// `ErrorCause::TensorBufferAlreadyLocked` while a guard returned by `map_read` or `map_write`
+    /// is alive for any handle of the tensor buffer.
+    ///
     /// Returns the number of bytes read from the tensor buffer.
-    pub fn read<T: 'static>(&self, data: &mut [T]) -> Result<usize, Error> {
-        if !self.element_type.is_compatible::<T>() {
+    pub fn read<T: TensorElement>(&self, data: &mut [T]) -> Result<usize, Error> {
+        if !is_accessible_as::<T>(self.element_type) {
             return Err(Error::new(
                 ErrorCause::IncompatibleReadType,
                 LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
             ));
         }
         let lock = self.lock_read()?;
+        check_locked_data(&lock)?;
         let src_size = self.packed_size()?;
         let dst_size = mem::size_of_val(data);
         if dst_size < src_size {
@@ -403,8 +1395,7 @@ impl<'a> TensorBuffer<'a> {
             ));
         }
         let to_copy = std::cmp::min(src_size, dst_size) / std::mem::size_of::<T>();
-        // SAFETY: lock.raw_data is always valid, it's guaranteed to be initialized by
-        // lock_read function.
+        // SAFETY: lock.raw_data is non-null and aligned for T, it's checked above.
         // data is a pointer to the start of the data buffer, it's valid as provided by safe
         // Rust code.
         // to_copy is the number of elements to copy, it's guaranteed that it won't overwrite
@@ -414,6 +1405,167 @@ impl<'a> TensorBuffer<'a> {
         }
         Ok(to_copy)
     }
+
+    /// Quantizes f32 data with the given parameters and writes it to the tensor buffer.
+    ///
+    /// The tensor buffer must be of type Int8, UInt8, Int16 or Int32, the parameters are usually
+    /// taken from the corresponding model tensor with `Tensor::quantization()`.
+    ///
+    /// Returns the number of elements written to the tensor buffer.
+    pub fn write_quantized_from_f32(
+        &mut self,
+        data: &[f32],
+        quantization: &Quantization,
+    ) -> Result<usize, Error> {
+        match self.element_type {
+            ElementType::Int8 => self.write_quantized::<i8>(data, quantization),
+            ElementType::UInt8 => self.write_quantized::<u8>(data, quantization),
+            ElementType::Int16 => self.write_quantized::<i16>(data, quantization),
+            ElementType::Int32 => self.write_quantized::<i32>(data, quantization),
+            _ => Err(Error::new(
+                ErrorCause::IncompatibleWriteType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+
+    /// Reads quantized data from the tensor buffer and dequantizes it to f32 with the given
+    /// parameters.
+    ///
+    /// The tensor buffer must be of type Int8, UInt8, Int16 or Int32.
+    ///
+    /// Returns the number of elements read from the tensor buffer.
+    pub fn read_dequantized_to_f32(
+        &self,
+        data: &mut [f32],
+        quantization: &Quantization,
+    ) -> Result<usize, Error> {
+        match self.element_type {
+            ElementType::Int8 => self.read_dequantized::<i8>(data, quantization),
+            ElementType::UInt8 => self.read_dequantized::<u8>(data, quantization),
+            ElementType::Int16 => self.read_dequantized::<i16>(data, quantization),
+            ElementType::Int32 => self.read_dequantized::<i32>(data, quantization),
+            _ => Err(Error::new(
+                ErrorCause::IncompatibleReadType,
+                LiteRtStatus_kLiteRtStatusErrorInvalidArgument,
+            )),
+        }
+    }
+
+    fn raw_tensor_type(&self) -> Result<LiteRtRankedTensorType, Error> {
+        let mut tensor_type = LiteRtRankedTensorType::default();
+        call_check_status!(
+            // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+            // a wrapper function.
+            // We assume that the output is valid if the return status is OK or don't use the output pointer.
+            unsafe { LiteRtGetTensorBufferTensorType(self.raw_tensor_buffer, &mut tensor_type) },
+            ErrorCause::GetTensorBufferTensorType
+        );
+        Ok(tensor_type)
+    }
+
+    fn quantization_params<'q>(
+        &self,
+        quantization: &'q Quantization,
+    ) -> Result<QuantizationParams<'q>, Error> {
+        if let Quantization::PerChannel { .. } = quantization {
+            let tensor_type = self.raw_tensor_type()?;
+            let rank = tensor_type.layout.rank() as usize;
+            QuantizationParams::new(quantization, &tensor_type.layout.dimensions[..rank])
+        } else {
+            QuantizationParams::new(quantization, &[])
+        }
+    }
+
+    fn write_quantized<Q: QuantizedStorage>(
+        &mut self,
+        data: &[f32],
+        quantization: &Quantization,
+    ) -> Result<usize, Error> {
+        let params = self.quantization_params(quantization)?;
+        let num_elements = self.packed_size()? / mem::size_of::<Q>();
+        let lock = self.lock_write::<Q>()?;
+        check_locked_data(&lock)?;
+        if num_elements < data.len() {
+            return Err(Error::new(
+                ErrorCause::TensorBufferTooSmall,
+                LiteRtStatus_kLiteRtStatusErrorRuntimeFailure,
+            ));
+        }
+        // SAFETY: lock.raw_data is non-null and aligned for Q, it's checked above.
+        // data.len() elements fit in the tensor buffer as checked above.
+        let dst = unsafe { std::slice::from_raw_parts_mut(lock.raw_data, data.len()) };
+        for (i, (dst, &value)) in dst.iter_mut().zip(data).enumerate() {
+            *dst = params.quantize(i, value);
+        }
+        Ok(data.len())
+    }
+
+    fn read_dequantized<Q: QuantizedStorage>(
+        &self,
+        data: &mut [f32],
+        quantization: &Quantization,
+    ) -> Result<usize, Error> {
+        let params = self.quantization_params(quantization)?;
+        let lock = self.lock_read::<Q>()?;
+        check_locked_data(&lock)?;
+        let num_elements = self.packed_size()? / mem::size_of::<Q>();
+        if data.len() < num_elements {
+            return Err(Error::new(
+                ErrorCause::ReadBufferTooSmall,
+                LiteRtStatus_kLiteRtStatusErrorRuntimeFailure,
+            ));
+        }
+        // SAFETY: lock.raw_data is non-null and aligned for Q, it's checked above.
+        // num_elements is the number of elements in the tensor buffer.
+        let src = unsafe { std::slice::from_raw_parts(lock.raw_data as *const Q, num_elements) };
+        for (i, (dst, &value)) in data.iter_mut().zip(src).enumerate() {
+            *dst = params.dequantize(i, value);
+        }
+        Ok(num_elements)
+    }
+}
+
+/// Returns a new handle to the same tensor buffer, the native buffer is reference counted and
+/// released when the last handle is dropped.
+///
+/// The handles share the memory of the tensor buffer and can be sent to other threads. LiteRT
+/// allows one lock at a time, so mapping, clearing or running a model on the buffer fails while
+/// it's mapped through another handle.
+impl Clone for TensorBuffer<'_> {
+    fn clone(&self) -> Self {
+        // SAFETY: self.raw_tensor_buffer is always valid, it's guaranteed to be initialized by
+        // a wrapper function.
+        let status = unsafe { LiteRtDuplicateTensorBuffer(self.raw_tensor_buffer) };
+        // Duplication only fails for a null tensor buffer, which a TensorBuffer never holds.
+        debug_assert_eq!(status, LiteRtStatus_kLiteRtStatusOk);
+        Self {
+            raw_tensor_buffer: self.raw_tensor_buffer,
+            element_type: self.element_type,
+            shared: Arc::clone(&self.shared),
+            _phantom: PhantomData {},
+        }
+    }
+}
+
+impl fmt::Debug for TensorBuffer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("TensorBuffer");
+        match self.tensor_type() {
+            Ok(tensor_type) => debug.field("tensor_type", &format_args!("{tensor_type}")),
+            Err(_) => debug.field("element_type", &self.element_type),
+        };
+        if let Ok(buffer_type) = self.buffer_type() {
+            debug.field("buffer_type", &buffer_type);
+        }
+        if let Ok(size) = self.size() {
+            debug.field("size", &size);
+        }
+        if let Ok(offset) = self.offset() {
+            debug.field("offset", &offset);
+        }
+        debug.finish_non_exhaustive()
+    }
 }
 
 impl Drop for TensorBuffer<'_> {
@@ -430,9 +1582,137 @@ impl Drop for TensorBuffer<'_> {
 mod tests {
     use super::*;
     #[test]
-    fn test_element_type_compatibility() {
-        assert!(ElementType::Bool.is_compatible::<bool>());
-        assert!(!ElementType::Bool.is_compatible::<u32>());
-        assert!(!ElementType::Float32.is_compatible::<u32>());
+    fn test_tensor_element_types() {
+        assert!(is_accessible_as::<u8>(ElementType::Bool));
+        assert!(!is_accessible_as::<i8>(ElementType::Bool));
+        assert_eq!(i8::ELEMENT_TYPE, ElementType::Int8);
+        assert_eq!(u8::ELEMENT_TYPE, ElementType::UInt8);
+        assert_eq!(i64::ELEMENT_TYPE, ElementType::Int64);
+        assert_eq!(PackedInt4::ELEMENT_TYPE, ElementType::Int4);
+    }
+
+    #[test]
+    fn test_packed_int4() {
+        let packed = PackedInt4::new(-3, 7);
+        assert_eq!(packed.to_bits(), 0x7d);
+        assert_eq!((packed.low(), packed.high()), (-3, 7));
+        let packed = PackedInt4::from_bits(0x8f);
+        assert_eq!((packed.low(), packed.high()), (-1, -8));
+    }
+
+    #[test]
+    fn test_shape() {
+        let shape = Shape::new(vec![Some(2), Some(3), Some(4)]);
+        assert_eq!(shape.rank(), 3);
+        assert_eq!(shape.num_elements(), Some(24));
+        assert_eq!(shape.strides(), Some(vec![12, 4, 1]));
+        assert_eq!(shape.to_string(), "[2, 3, 4]");
+
+        let dynamic = Shape::new(vec![None, Some(3)]);
+        assert!(!dynamic.is_static());
+        assert_eq!(dynamic.num_elements(), None);
+        assert_eq!(dynamic.strides(), None);
+        assert_eq!(dynamic.to_string(), "[?, 3]");
+
+        let scalar = Shape::new(vec![]);
+        assert_eq!(scalar.num_elements(), Some(1));
+        assert_eq!(scalar.to_string(), "[]");
+    }
+
+    #[test]
+    fn test_aligned_host_memory() {
+        let mut memory = AlignedHostMemory::<f32>::new(10, 64).unwrap();
+        assert_eq!(memory.len(), 10);
+        assert!((memory.as_ptr() as usize).is_multiple_of(64));
+        assert!(memory.iter().all(|&value| value == 0.0));
+        memory[9] = 1.0;
+        assert_eq!(memory[9], 1.0);
+        assert!((AlignedHostMemory::<u64>::new(0, 1).unwrap().as_ptr() as usize).is_multiple_of(8));
+        assert!(AlignedHostMemory::<u8>::new(4, 3).is_err());
+        assert!(AlignedHostMemory::<u64>::new(usize::MAX, 64).is_err());
+    }
+
+    #[test]
+    fn test_shared_state_in_use() {
+        let shared = SharedState::default();
+        let in_use = shared.acquire().unwrap();
+        assert!(shared.acquire().is_err());
+        drop(in_use);
+        assert!(shared.acquire().is_ok());
+    }
+
+    #[test]
+    fn test_single_read_lock() {
+        // map_read and read lock through the state shared by all the handles of a buffer.
+        let shared = Arc::new(SharedState::default());
+        let clone = Arc::clone(&shared);
+        let read_guard = shared.acquire().unwrap();
+        let error = shared.acquire().err().unwrap();
+        assert_eq!(error.cause(), ErrorCause::TensorBufferAlreadyLocked);
+        let error = clone.acquire().err().unwrap();
+        assert_eq!(error.cause(), ErrorCause::TensorBufferAlreadyLocked);
+        drop(read_guard);
+        assert!(clone.acquire().is_ok());
+    }
+
+    #[test]
+    fn test_shape_to_layout() {
+        let mut shape = Shape::new(vec![Some(2), None, Some(4)]);
+        let layout = shape.to_layout().unwrap();
+        assert_eq!(layout.rank(), 3);
+        assert!(!layout.has_strides());
+        assert_eq!(Shape::from_layout(&layout), shape);
+        shape.strides = Some(vec![8, 4, 1]);
+        assert_eq!(Shape::from_layout(&shape.to_layout().unwrap()), shape);
+        assert!(Shape::new(vec![Some(1); 9]).to_layout().is_err());
+        assert!(Shape::new(vec![Some(1 << 40)]).to_layout().is_err());
+    }
+
+    #[test]
+    fn test_tensor_type_byte_size() {
+        let shape = Shape::new(vec![Some(3), Some(5)]);
+        let f32_type = TensorType {
+            element_type: ElementType::Float32,
+            shape: shape.clone(),
+        };
+        assert_eq!(f32_type.byte_size(), Some(60));
+        assert_eq!(f32_type.to_string(), "Float32[3, 5]");
+        let int4_type = TensorType {
+            element_type: ElementType::Int4,
+            shape,
+        };
+        assert_eq!(int4_type.byte_size(), Some(8));
+        let string_type = TensorType {
+            element_type: ElementType::TfString,
+            shape: Shape::new(vec![Some(1)]),
+        };
+        assert_eq!(string_type.byte_size(), None);
+    }
+
+    #[test]
+    fn test_per_tensor_quantization() {
+        let quantization = Quantization::PerTensor {
+            scale: 0.5,
+            zero_point: -3,
+        };
+        let params = QuantizationParams::new(&quantization, &[]).unwrap();
+        assert_eq!(params.quantize::<i8>(0, 1.0), -1);
+        assert_eq!(params.quantize::<i8>(0, 1000.0), i8::MAX);
+        assert_eq!(params.dequantize::<i8>(0, -1), 1.0);
+    }
+
+    #[test]
+    fn test_per_channel_quantization() {
+        let quantization = Quantization::PerChannel {
+            scales: vec![1.0, 2.0],
+            zero_points: vec![0, 10],
+            quantized_dimension: 0,
+        };
+        let params = QuantizationParams::new(&quantization, &[2, 3]).unwrap();
+        assert_eq!(params.quantize::<u8>(2, 4.0), 4);
+        assert_eq!(params.quantize::<u8>(3, 4.0), 12);
+        assert_eq!(params.dequantize::<u8>(5, 12), 4.0);
+        assert!(QuantizationParams::new(&quantization, &[3, 2]).is_err());
+        assert!(QuantizationParams::new(&Quantization::None, &[]).is_err());
     }
 }
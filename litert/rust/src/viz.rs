@@ -0,0 +1,370 @@
+//! Graphviz DOT and Mermaid rendering of subgraphs.
+//!
+//! Operations are rendered as nodes labeled with their code and key options, tensors as edges
+//! labeled with their element type and shape. Subgraph inputs and outputs get their own nodes,
+//! constant weights are rendered as dashed gray nodes. Operations can be colored by the
+//! accelerator they run on to show how a model is partitioned.
+//!
+//! ```rust,no_run
+//! let model = litert::Model::create_model_from_file("model.tflite")?;
+//! let dot = litert::viz::to_dot(&model.main_subgraph()?, &litert::viz::VizOptions::default())?;
+//! std::fs::write("model.dot", dot).unwrap();
+//! # Ok::<(), litert::Error>(())
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+use crate::compiled_model::LiteRtHwAccelerator;
+use crate::error::Error;
+use crate::model::Subgraph;
+use crate::op_options::{FusedActivation, OpOptions};
+use crate::summary::{summarize_subgraph, SubgraphSummary, TensorSummary};
+use crate::tensor_buffer::Shape;
+
+/// Options for rendering a subgraph.
+#[derive(Default)]
+pub struct VizOptions<'f> {
+    /// Returns the accelerator the operation at the given index of the subgraph was assigned to,
+    /// operations are colored by accelerator.
+    pub accelerator: Option<&'f dyn Fn(usize) -> Option<LiteRtHwAccelerator>>,
+}
+
+/// Renders the subgraph as a Graphviz DOT digraph.
+pub fn to_dot(subgraph: &Subgraph<'_>, options: &VizOptions<'_>) -> Result<String, Error> {
+    Ok(render(&Graph::new(subgraph, options)?, render_dot))
+}
+
+/// Renders the subgraph as a Mermaid flowchart.
+pub fn to_mermaid(subgraph: &Subgraph<'_>, options: &VizOptions<'_>) -> Result<String, Error> {
+    Ok(render(&Graph::new(subgraph, options)?, render_mermaid))
+}
+
+// A subgraph with the rendered label and color of every operation.
+struct Graph {
+    subgraph: SubgraphSummary,
+    op_labels: Vec<String>,
+    op_colors: Vec<Option<&'static str>>,
+}
+
+impl Graph {
+    fn new(subgraph: &Subgraph<'_>, options: &VizOptions<'_>) -> Result<Self, Error> {
+        let summary = summarize_subgraph(0, false, subgraph)?;
+        let mut op_labels = Vec::with_capacity(summary.ops.len());
+        let mut op_colors = Vec::with_capacity(summary.ops.len());
+        for (i, (op, op_summary)) in subgraph.ops()?.zip(&summary.ops).enumerate() {
+            let key_options = key_options(&op?.options()?);
+            op_labels.push(if key_options.is_empty() {
+                format!("{:?}", op_summary.code)
+            } else {
+                format!("{:?}\n{}", op_summary.code, key_options.join(", "))
+            });
+            op_colors.push(
+                options
+                    .accelerator
+                    .and_then(|accelerator| accelerator(i))
+                    .map(|accelerator| match accelerator {
+                        LiteRtHwAccelerator::None => "#ffffff",
+                        LiteRtHwAccelerator::Cpu => "#cfe2f3",
+                        LiteRtHwAccelerator::Gpu => "#d9ead3",
+                        LiteRtHwAccelerator::Npu => "#fce5cd",
+                    }),
+            );
+        }
+        Ok(Self {
+            subgraph: summary,
+            op_labels,
+            op_colors,
+        })
+    }
+
+    // Returns the edges of the graph as (source node, target node, tensor index).
+    fn edges(&self) -> Vec<(String, String, usize)> {
+        let mut producers = HashMap::new();
+        for (i, op) in self.subgraph.ops.iter().enumerate() {
+            for &output in &op.outputs {
+                producers.insert(output, i);
+            }
+        }
+        let source = |tensor: usize| match producers.get(&tensor) {
+            Some(op) => format!("op{op}"),
+            None => tensor_node(&self.subgraph.tensors[tensor], tensor),
+        };
+        let mut edges = Vec::new();
+        for (i, op) in self.subgraph.ops.iter().enumerate() {
+            for &input in &op.inputs {
+                edges.push((source(input), format!("op{i}"), input));
+            }
+        }
+        for &output in &self.subgraph.outputs {
+            edges.push((source(output), format!("out{output}"), output));
+        }
+        edges
+    }
+
+    // Returns the tensors rendered as nodes: subgraph inputs and constant or unproduced operands.
+    fn source_tensors(&self) -> Vec<usize> {
+        let produced: Vec<usize> = self
+            .subgraph
+            .ops
+            .iter()
+            .flat_map(|op| op.outputs.iter().copied())
+            .collect();
+        let mut tensors: Vec<usize> = self.subgraph.inputs.clone();
+        let operands = self.subgraph.ops.iter().flat_map(|op| op.inputs.iter());
+        for &tensor in operands.chain(&self.subgraph.outputs) {
+            if !produced.contains(&tensor) && !tensors.contains(&tensor) {
+                tensors.push(tensor);
+            }
+        }
+        tensors
+    }
+}
+
+fn tensor_node(tensor: &TensorSummary, index: usize) -> String {
+    if tensor.is_constant {
+        format!("w{index}")
+    } else {
+        format!("in{index}")
+    }
+}
+
+fn tensor_type_label(tensor: &TensorSummary) -> String {
+    match &tensor.shape {
+        Some(dims) => format!("{:?}{}", tensor.element_type, Shape::new(dims.clone())),
+        None => format!("{:?}[*]", tensor.element_type),
+    }
+}
+
+// Returns the options worth showing in the node of an operation.
+fn key_options(options: &OpOptions) -> Vec<String> {
+    let mut result = Vec::new();
+    let activation = |fused_activation: FusedActivation| {
+        if fused_activation != FusedActivation::None {
+            Some(format!("{fused_activation:?}"))
+        } else {
+            None
+        }
+    };
+    match options {
+        OpOptions::Add(o) | OpOptions::Sub(o) | OpOptions::Mul(o) | OpOptions::Div(o) => {
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::Conv2d(o) => {
+            result.push(format!("stride {}x{}", o.stride_h, o.stride_w));
+            if o.dilation_h != 1 || o.dilation_w != 1 {
+                result.push(format!("dilation {}x{}", o.dilation_h, o.dilation_w));
+            }
+            result.push(format!("{:?}", o.padding));
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::DepthwiseConv2d(o) => {
+            result.push(format!("stride {}x{}", o.stride_h, o.stride_w));
+            if o.depth_multiplier != 1 {
+                result.push(format!("multiplier {}", o.depth_multiplier));
+            }
+            result.push(format!("{:?}", o.padding));
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::TransposeConv(o) => {
+            result.push(format!("stride {}x{}", o.stride_h, o.stride_w));
+            result.push(format!("{:?}", o.padding));
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::AveragePool2d(o) | OpOptions::MaxPool2d(o) | OpOptions::L2Pool2d(o) => {
+            result.push(format!("filter {}x{}", o.filter_height, o.filter_width));
+            result.push(format!("stride {}x{}", o.stride_h, o.stride_w));
+            result.push(format!("{:?}", o.padding));
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::FullyConnected(o) => result.extend(activation(o.fused_activation)),
+        OpOptions::Concatenation(o) => {
+            result.push(format!("axis {}", o.axis));
+            result.extend(activation(o.fused_activation));
+        }
+        OpOptions::Gather(o) => result.push(format!("axis {}", o.axis)),
+        OpOptions::Pack(o) => result.push(format!("axis {}", o.axis)),
+        OpOptions::Unpack(o) => result.push(format!("axis {}", o.axis)),
+        OpOptions::Reshape(o) => result.push(format!("shape {:?}", o.new_shape)),
+        _ => {}
+    }
+    result
+}
+
+fn escape_dot(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_mermaid(label: &str) -> String {
+    label.replace('"', "#quot;").replace('\n', "<br/>")
+}
+
+fn render_dot(graph: &Graph, dot: &mut String) -> fmt::Result {
+    let tensors = &graph.subgraph.tensors;
+    writeln!(dot, "digraph litert {{")?;
+    writeln!(
+        dot,
+        "  node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\"];"
+    )?;
+    for tensor in graph.source_tensors() {
+        let node = tensor_node(&tensors[tensor], tensor);
+        let label = escape_dot(&tensors[tensor].name);
+        if tensors[tensor].is_constant {
+            writeln!(
+                dot,
+                "  {node} [label=\"{label}\", style=\"dashed,filled\", fillcolor=\"#eeeeee\"];"
+            )?;
+        } else {
+            writeln!(dot, "  {node} [label=\"{label}\", shape=ellipse];")?;
+        }
+    }
+    for (i, label) in graph.op_labels.iter().enumerate() {
+        write!(dot, "  op{i} [label=\"{}\"", escape_dot(label))?;
+        if let Some(color) = graph.op_colors[i] {
+            write!(dot, ", fillcolor=\"{color}\"")?;
+        }
+        writeln!(dot, "];")?;
+    }
+    for &output in &graph.subgraph.outputs {
+        let label = escape_dot(&tensors[output].name);
+        writeln!(dot, "  out{output} [label=\"{label}\", shape=ellipse];")?;
+    }
+    for (source, target, tensor) in graph.edges() {
+        let label = escape_dot(&tensor_type_label(&tensors[tensor]));
+        write!(dot, "  {source} -> {target} [label=\"{label}\"")?;
+        if tensors[tensor].is_constant {
+            write!(dot, ", style=dashed, color=\"#999999\"")?;
+        }
+        writeln!(dot, "];")?;
+    }
+    writeln!(dot, "}}")
+}
+
+fn render_mermaid(graph: &Graph, mermaid: &mut String) -> fmt::Result {
+    let tensors = &graph.subgraph.tensors;
+    let mut weights = Vec::new();
+    writeln!(mermaid, "flowchart TD")?;
+    for tensor in graph.source_tensors() {
+        let node = tensor_node(&tensors[tensor], tensor);
+        let label = escape_mermaid(&tensors[tensor].name);
+        if tensors[tensor].is_constant {
+            writeln!(mermaid, "  {node}[\"{label}\"]")?;
+            weights.push(node);
+        } else {
+            writeln!(mermaid, "  {node}([\"{label}\"])")?;
+        }
+    }
+    for (i, label) in graph.op_labels.iter().enumerate() {
+        writeln!(mermaid, "  op{i}[\"{}\"]", escape_mermaid(label))?;
+    }
+    for &output in &graph.subgraph.outputs {
+        let label = escape_mermaid(&tensors[output].name);
+        writeln!(mermaid, "  out{output}([\"{label}\"])")?;
+    }
+    for (source, target, tensor) in graph.edges() {
+        let label = escape_mermaid(&tensor_type_label(&tensors[tensor]));
+        let arrow = if tensors[tensor].is_constant {
+            "-.->"
+        } else {
+            "-->"
+        };
+        writeln!(mermaid, "  {source} {arrow}|\"{label}\"| {target}")?;
+    }
+    if !weights.is_empty() {
+        writeln!(
+            mermaid,
+            "  classDef weights fill:#eeeeee,stroke-dasharray:3 3"
+        )?;
+        writeln!(mermaid, "  class {} weights", weights.join(","))?;
+    }
+    for (i, color) in graph.op_colors.iter().enumerate() {
+        if let Some(color) = color {
+            writeln!(mermaid, "  style op{i} fill:{color}")?;
+        }
+    }
+    Ok(())
+}
+
+// Renders the graph into a string, writing to a String can't fail.
+fn render(graph: &Graph, renderer: fn(&Graph, &mut String) -> fmt::Result) -> String {
+    let mut result = String::new();
+    renderer(graph, &mut result).expect("writing to a String failed");
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Quantization;
+    use crate::op_code::OpCode;
+    use crate::summary::OpSummary;
+    use crate::ElementType;
+
+    fn tensor(name: &str, is_constant: bool) -> TensorSummary {
+        TensorSummary {
+            name: name.to_string(),
+            element_type: ElementType::Float32,
+            shape: Some(vec![None, Some(4)]),
+            quantization: Quantization::None,
+            is_constant,
+        }
+    }
+
+    fn graph() -> Graph {
+        Graph {
+            subgraph: SubgraphSummary {
+                index: 0,
+                is_main: true,
+                inputs: vec![0],
+                outputs: vec![2],
+                tensors: vec![tensor("x", false), tensor("w", true), tensor("y", false)],
+                ops: vec![OpSummary {
+                    code: OpCode::TflMul,
+                    inputs: vec![0, 1],
+                    outputs: vec![2],
+                    options: None,
+                }],
+            },
+            op_labels: vec!["TflMul\nRelu".to_string()],
+            op_colors: vec![Some("#d9ead3")],
+        }
+    }
+
+    #[test]
+    fn test_render_dot() {
+        assert_eq!(
+            render(&graph(), render_dot),
+            "digraph litert {\n\
+             \x20 node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\"];\n\
+             \x20 in0 [label=\"x\", shape=ellipse];\n\
+             \x20 w1 [label=\"w\", style=\"dashed,filled\", fillcolor=\"#eeeeee\"];\n\
+             \x20 op0 [label=\"TflMul\\nRelu\", fillcolor=\"#d9ead3\"];\n\
+             \x20 out2 [label=\"y\", shape=ellipse];\n\
+             \x20 in0 -> op0 [label=\"Float32[?, 4]\"];\n\
+             \x20 w1 -> op0 [label=\"Float32[?, 4]\", style=dashed, color=\"#999999\"];\n\
+             \x20 op0 -> out2 [label=\"Float32[?, 4]\"];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_render_mermaid() {
+        assert_eq!(
+            render(&graph(), render_mermaid),
+            "flowchart TD\n\
+             \x20 in0([\"x\"])\n\
+             \x20 w1[\"w\"]\n\
+             \x20 op0[\"TflMul<br/>Relu\"]\n\
+             \x20 out2([\"y\"])\n\
+             \x20 in0 -->|\"Float32[?, 4]\"| op0\n\
+             \x20 w1 -.->|\"Float32[?, 4]\"| op0\n\
+             \x20 op0 -->|\"Float32[?, 4]\"| out2\n\
+             \x20 classDef weights fill:#eeeeee,stroke-dasharray:3 3\n\
+             \x20 class w1 weights\n\
+             \x20 style op0 fill:#d9ead3\n"
+        );
+    }
+}